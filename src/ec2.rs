@@ -1,16 +1,23 @@
 use std::{
+    collections::HashMap,
     error,
     fmt::{Debug, Display},
     io,
+    time::Duration,
 };
 
 use aws_credential_types::Credentials;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
 use ureq::Response;
 
-use crate::request::{self, sign_request, with_retry};
+use crate::{
+    query,
+    request::{self, presign_url, sign_request, with_retry},
+    waiter::Waiter,
+};
 
 const SERVICE_NAME: &str = "ec2";
 
@@ -92,53 +99,656 @@ impl Api {
         }
     }
 
+    pub fn allocate_address(
+        &self,
+        mut input: AllocateAddressInput,
+    ) -> Result<AllocateAddressOutput> {
+        // Addresses are allocated for use in a VPC unless asked otherwise.
+        input.domain.get_or_insert(Domain::Vpc);
+        self.call("AllocateAddress", &input)
+    }
+
+    pub fn associate_address(
+        &self,
+        input: AssociateAddressInput,
+    ) -> Result<AssociateAddressOutput> {
+        self.call("AssociateAddress", &input)
+    }
+
+    pub fn assign_private_ip_addresses(
+        &self,
+        input: AssignPrivateIpAddressesInput,
+    ) -> Result<AssignPrivateIpAddressesOutput> {
+        self.call("AssignPrivateIpAddresses", &input)
+    }
+
+    pub fn attach_network_interface(
+        &self,
+        input: AttachNetworkInterfaceInput,
+    ) -> Result<AttachNetworkInterfaceOutput> {
+        self.call("AttachNetworkInterface", &input)
+    }
+
     pub fn attach_volume(&self, input: AttachVolumeInput) -> Result<AttachVolumeOutput> {
-        let req = ureq::post(&self.url());
+        self.call("AttachVolume", &input)
+    }
 
-        let params = vec![
-            ("Action".into(), "AttachVolume".into()),
-            ("Version".into(), "2016-11-15".into()),
-            ("Device".into(), input.device),
-            ("InstanceId".into(), input.instance_id),
-            ("VolumeId".into(), input.volume_id),
-        ];
-        self.send(req, params)
-            .and_then(|response| {
-                let body = response.into_reader();
-                let output = serde_xml_rs::from_reader(body)?;
-                Ok(output)
-            })
-            .map_err(Into::into)
+    pub fn authorize_security_group_egress(
+        &self,
+        input: AuthorizeSecurityGroupEgressInput,
+    ) -> Result<AuthorizeSecurityGroupEgressOutput> {
+        self.call("AuthorizeSecurityGroupEgress", &input)
     }
 
-    pub fn describe_volumes(&self, input: DescribeVolumesInput) -> Result<DescribeVolumesOutput> {
-        let url = &self.url();
-        let req = ureq::post(&format!("{}/", url));
+    pub fn authorize_security_group_ingress(
+        &self,
+        input: AuthorizeSecurityGroupIngressInput,
+    ) -> Result<AuthorizeSecurityGroupIngressOutput> {
+        self.call("AuthorizeSecurityGroupIngress", &input)
+    }
 
-        let mut params = vec![
-            ("Action".into(), "DescribeVolumes".into()),
-            ("Version".into(), "2016-11-15".into()),
-        ];
-        if let Some(filters) = input.filters {
-            params.extend(filters.to_params("Filter"));
+    pub fn cancel_spot_instance_requests(
+        &self,
+        input: CancelSpotInstanceRequestsInput,
+    ) -> Result<CancelSpotInstanceRequestsOutput> {
+        self.call("CancelSpotInstanceRequests", &input)
+    }
+
+    pub fn copy_image(&self, input: CopyImageInput) -> Result<CopyImageOutput> {
+        self.call("CopyImage", &input)
+    }
+
+    pub fn copy_snapshot(&self, mut input: CopySnapshotInput) -> Result<CopySnapshotOutput> {
+        // Copies out of another region need a request presigned for the
+        // source region, which is generated here unless the caller has one.
+        if input.presigned_url.is_none() && input.source_region != self.region {
+            input.presigned_url =
+                Some(self.presign_copy_snapshot(&input.source_region, &input.source_snapshot_id)?);
+        }
+        if input.presigned_url.is_some() && input.destination_region.is_none() {
+            input.destination_region = Some(self.region.clone());
+        }
+
+        self.call("CopySnapshot", &input)
+    }
+
+    pub fn create_image(&self, input: CreateImageInput) -> Result<CreateImageOutput> {
+        self.call("CreateImage", &input)
+    }
+
+    pub fn create_launch_template(
+        &self,
+        input: CreateLaunchTemplateInput,
+    ) -> Result<CreateLaunchTemplateOutput> {
+        self.call("CreateLaunchTemplate", &input)
+    }
+
+    pub fn create_launch_template_version(
+        &self,
+        input: CreateLaunchTemplateVersionInput,
+    ) -> Result<CreateLaunchTemplateVersionOutput> {
+        self.call("CreateLaunchTemplateVersion", &input)
+    }
+
+    pub fn create_network_interface(
+        &self,
+        input: CreateNetworkInterfaceInput,
+    ) -> Result<CreateNetworkInterfaceOutput> {
+        self.call("CreateNetworkInterface", &input)
+    }
+
+    pub fn create_security_group(
+        &self,
+        input: CreateSecurityGroupInput,
+    ) -> Result<CreateSecurityGroupOutput> {
+        self.call("CreateSecurityGroup", &input)
+    }
+
+    pub fn create_snapshot(&self, input: CreateSnapshotInput) -> Result<CreateSnapshotOutput> {
+        self.call("CreateSnapshot", &input)
+    }
+
+    pub fn create_tags(&self, input: CreateTagsInput) -> Result<CreateTagsOutput> {
+        self.call("CreateTags", &input)
+    }
+
+    pub fn create_volume(&self, input: CreateVolumeInput) -> Result<CreateVolumeOutput> {
+        self.call("CreateVolume", &input)
+    }
+
+    pub fn delete_network_interface(
+        &self,
+        input: DeleteNetworkInterfaceInput,
+    ) -> Result<DeleteNetworkInterfaceOutput> {
+        self.call("DeleteNetworkInterface", &input)
+    }
+
+    pub fn delete_security_group(
+        &self,
+        input: DeleteSecurityGroupInput,
+    ) -> Result<DeleteSecurityGroupOutput> {
+        self.call("DeleteSecurityGroup", &input)
+    }
+
+    pub fn delete_snapshot(&self, input: DeleteSnapshotInput) -> Result<DeleteSnapshotOutput> {
+        self.call("DeleteSnapshot", &input)
+    }
+
+    pub fn delete_tags(&self, input: DeleteTagsInput) -> Result<DeleteTagsOutput> {
+        self.call("DeleteTags", &input)
+    }
+
+    pub fn delete_volume(&self, input: DeleteVolumeInput) -> Result<DeleteVolumeOutput> {
+        self.call("DeleteVolume", &input)
+    }
+
+    pub fn deregister_image(&self, input: DeregisterImageInput) -> Result<DeregisterImageOutput> {
+        self.call("DeregisterImage", &input)
+    }
+
+    pub fn describe_addresses(
+        &self,
+        input: DescribeAddressesInput,
+    ) -> Result<DescribeAddressesOutput> {
+        self.call("DescribeAddresses", &input)
+    }
+
+    pub fn describe_availability_zones(
+        &self,
+        input: DescribeAvailabilityZonesInput,
+    ) -> Result<DescribeAvailabilityZonesOutput> {
+        self.call("DescribeAvailabilityZones", &input)
+    }
+
+    pub fn describe_images(&self, input: DescribeImagesInput) -> Result<DescribeImagesOutput> {
+        self.call("DescribeImages", &input)
+    }
+
+    pub fn describe_images_paginator(
+        &self,
+        input: DescribeImagesInput,
+    ) -> DescribeImagesPaginator<'_> {
+        DescribeImagesPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_instance_attribute(
+        &self,
+        input: DescribeInstanceAttributeInput,
+    ) -> Result<DescribeInstanceAttributeOutput> {
+        self.call("DescribeInstanceAttribute", &input)
+    }
+
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> Result<DescribeInstancesOutput> {
+        self.call("DescribeInstances", &input)
+    }
+
+    pub fn describe_instances_paginator(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> DescribeInstancesPaginator<'_> {
+        DescribeInstancesPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_launch_template_versions(
+        &self,
+        input: DescribeLaunchTemplateVersionsInput,
+    ) -> Result<DescribeLaunchTemplateVersionsOutput> {
+        self.call("DescribeLaunchTemplateVersions", &input)
+    }
+
+    pub fn describe_launch_template_versions_paginator(
+        &self,
+        input: DescribeLaunchTemplateVersionsInput,
+    ) -> DescribeLaunchTemplateVersionsPaginator<'_> {
+        DescribeLaunchTemplateVersionsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_launch_templates(
+        &self,
+        input: DescribeLaunchTemplatesInput,
+    ) -> Result<DescribeLaunchTemplatesOutput> {
+        self.call("DescribeLaunchTemplates", &input)
+    }
+
+    pub fn describe_launch_templates_paginator(
+        &self,
+        input: DescribeLaunchTemplatesInput,
+    ) -> DescribeLaunchTemplatesPaginator<'_> {
+        DescribeLaunchTemplatesPaginator {
+            api: self,
+            input,
+            done: false,
         }
-        if let Some(max_results) = input.max_results {
-            params.push(("MaxResults".into(), max_results.to_string()));
+    }
+
+    pub fn describe_network_interfaces(
+        &self,
+        input: DescribeNetworkInterfacesInput,
+    ) -> Result<DescribeNetworkInterfacesOutput> {
+        self.call("DescribeNetworkInterfaces", &input)
+    }
+
+    pub fn describe_network_interfaces_paginator(
+        &self,
+        input: DescribeNetworkInterfacesInput,
+    ) -> DescribeNetworkInterfacesPaginator<'_> {
+        DescribeNetworkInterfacesPaginator {
+            api: self,
+            input,
+            done: false,
         }
-        if let Some(next_token) = input.next_token {
-            params.push(("NextToken".into(), next_token));
+    }
+
+    pub fn describe_route_tables(
+        &self,
+        input: DescribeRouteTablesInput,
+    ) -> Result<DescribeRouteTablesOutput> {
+        self.call("DescribeRouteTables", &input)
+    }
+
+    pub fn describe_route_tables_paginator(
+        &self,
+        input: DescribeRouteTablesInput,
+    ) -> DescribeRouteTablesPaginator<'_> {
+        DescribeRouteTablesPaginator {
+            api: self,
+            input,
+            done: false,
         }
-        if let Some(volume_ids) = input.volume_ids {
-            params.extend(volume_ids.to_params("VolumeId"));
+    }
+
+    pub fn describe_security_groups(
+        &self,
+        input: DescribeSecurityGroupsInput,
+    ) -> Result<DescribeSecurityGroupsOutput> {
+        self.call("DescribeSecurityGroups", &input)
+    }
+
+    pub fn describe_security_groups_paginator(
+        &self,
+        input: DescribeSecurityGroupsInput,
+    ) -> DescribeSecurityGroupsPaginator<'_> {
+        DescribeSecurityGroupsPaginator {
+            api: self,
+            input,
+            done: false,
         }
+    }
 
-        self.send(req, params)
-            .and_then(|response| {
-                let body = response.into_reader();
-                let output = serde_xml_rs::from_reader(body)?;
-                Ok(output)
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsInput,
+    ) -> Result<DescribeSnapshotsOutput> {
+        self.call("DescribeSnapshots", &input)
+    }
+
+    pub fn describe_snapshots_paginator(
+        &self,
+        input: DescribeSnapshotsInput,
+    ) -> DescribeSnapshotsPaginator<'_> {
+        DescribeSnapshotsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_spot_instance_requests(
+        &self,
+        input: DescribeSpotInstanceRequestsInput,
+    ) -> Result<DescribeSpotInstanceRequestsOutput> {
+        self.call("DescribeSpotInstanceRequests", &input)
+    }
+
+    pub fn describe_spot_instance_requests_paginator(
+        &self,
+        input: DescribeSpotInstanceRequestsInput,
+    ) -> DescribeSpotInstanceRequestsPaginator<'_> {
+        DescribeSpotInstanceRequestsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_spot_price_history(
+        &self,
+        input: DescribeSpotPriceHistoryInput,
+    ) -> Result<DescribeSpotPriceHistoryOutput> {
+        self.call("DescribeSpotPriceHistory", &input)
+    }
+
+    pub fn describe_spot_price_history_paginator(
+        &self,
+        input: DescribeSpotPriceHistoryInput,
+    ) -> DescribeSpotPriceHistoryPaginator<'_> {
+        DescribeSpotPriceHistoryPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_subnets(&self, input: DescribeSubnetsInput) -> Result<DescribeSubnetsOutput> {
+        self.call("DescribeSubnets", &input)
+    }
+
+    pub fn describe_subnets_paginator(
+        &self,
+        input: DescribeSubnetsInput,
+    ) -> DescribeSubnetsPaginator<'_> {
+        DescribeSubnetsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_tags(&self, input: DescribeTagsInput) -> Result<DescribeTagsOutput> {
+        self.call("DescribeTags", &input)
+    }
+
+    pub fn describe_tags_paginator(&self, input: DescribeTagsInput) -> DescribeTagsPaginator<'_> {
+        DescribeTagsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn describe_volumes(&self, input: DescribeVolumesInput) -> Result<DescribeVolumesOutput> {
+        self.call("DescribeVolumes", &input)
+    }
+
+    pub fn describe_volumes_modifications(
+        &self,
+        input: DescribeVolumesModificationsInput,
+    ) -> Result<DescribeVolumesModificationsOutput> {
+        self.call("DescribeVolumesModifications", &input)
+    }
+
+    pub fn describe_vpcs(&self, input: DescribeVpcsInput) -> Result<DescribeVpcsOutput> {
+        self.call("DescribeVpcs", &input)
+    }
+
+    pub fn describe_vpcs_paginator(&self, input: DescribeVpcsInput) -> DescribeVpcsPaginator<'_> {
+        DescribeVpcsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn detach_network_interface(
+        &self,
+        input: DetachNetworkInterfaceInput,
+    ) -> Result<DetachNetworkInterfaceOutput> {
+        self.call("DetachNetworkInterface", &input)
+    }
+
+    pub fn detach_volume(&self, input: DetachVolumeInput) -> Result<DetachVolumeOutput> {
+        self.call("DetachVolume", &input)
+    }
+
+    pub fn disassociate_address(
+        &self,
+        input: DisassociateAddressInput,
+    ) -> Result<DisassociateAddressOutput> {
+        self.call("DisassociateAddress", &input)
+    }
+
+    pub fn get_console_output(
+        &self,
+        input: GetConsoleOutputInput,
+    ) -> Result<GetConsoleOutputOutput> {
+        self.call("GetConsoleOutput", &input)
+    }
+
+    pub fn get_console_screenshot(
+        &self,
+        input: GetConsoleScreenshotInput,
+    ) -> Result<GetConsoleScreenshotOutput> {
+        self.call("GetConsoleScreenshot", &input)
+    }
+
+    pub fn modify_instance_attribute(
+        &self,
+        input: ModifyInstanceAttributeInput,
+    ) -> Result<ModifyInstanceAttributeOutput> {
+        self.call("ModifyInstanceAttribute", &input)
+    }
+
+    pub fn modify_instance_metadata_options(
+        &self,
+        input: ModifyInstanceMetadataOptionsInput,
+    ) -> Result<ModifyInstanceMetadataOptionsOutput> {
+        self.call("ModifyInstanceMetadataOptions", &input)
+    }
+
+    pub fn modify_network_interface_attribute(
+        &self,
+        input: ModifyNetworkInterfaceAttributeInput,
+    ) -> Result<ModifyNetworkInterfaceAttributeOutput> {
+        self.call("ModifyNetworkInterfaceAttribute", &input)
+    }
+
+    pub fn modify_volume(&self, input: ModifyVolumeInput) -> Result<ModifyVolumeOutput> {
+        self.call("ModifyVolume", &input)
+    }
+
+    pub fn reboot_instances(&self, input: RebootInstancesInput) -> Result<RebootInstancesOutput> {
+        self.call("RebootInstances", &input)
+    }
+
+    pub fn register_image(&self, input: RegisterImageInput) -> Result<RegisterImageOutput> {
+        self.call("RegisterImage", &input)
+    }
+
+    pub fn release_address(&self, input: ReleaseAddressInput) -> Result<ReleaseAddressOutput> {
+        self.call("ReleaseAddress", &input)
+    }
+
+    pub fn request_spot_instances(
+        &self,
+        input: RequestSpotInstancesInput,
+    ) -> Result<RequestSpotInstancesOutput> {
+        self.call("RequestSpotInstances", &input)
+    }
+
+    pub fn revoke_security_group_egress(
+        &self,
+        input: RevokeSecurityGroupEgressInput,
+    ) -> Result<RevokeSecurityGroupEgressOutput> {
+        self.call("RevokeSecurityGroupEgress", &input)
+    }
+
+    pub fn revoke_security_group_ingress(
+        &self,
+        input: RevokeSecurityGroupIngressInput,
+    ) -> Result<RevokeSecurityGroupIngressOutput> {
+        self.call("RevokeSecurityGroupIngress", &input)
+    }
+
+    pub fn run_instances(&self, input: RunInstancesInput) -> Result<RunInstancesOutput> {
+        self.call("RunInstances", &input)
+    }
+
+    pub fn start_instances(&self, input: StartInstancesInput) -> Result<StartInstancesOutput> {
+        self.call("StartInstances", &input)
+    }
+
+    pub fn stop_instances(&self, input: StopInstancesInput) -> Result<StopInstancesOutput> {
+        self.call("StopInstances", &input)
+    }
+
+    pub fn terminate_instances(
+        &self,
+        input: TerminateInstancesInput,
+    ) -> Result<TerminateInstancesOutput> {
+        self.call("TerminateInstances", &input)
+    }
+
+    pub fn wait_until_image_available(
+        &self,
+        input: DescribeImagesInput,
+        timeout: Duration,
+    ) -> Result<DescribeImagesOutput> {
+        Waiter::new("images to be available")
+            .timeout(timeout)
+            .success(|output: &DescribeImagesOutput| {
+                all(image_states(output), |s| *s == ImageState::Available)
+            })
+            .failure(|output| image_states(output).contains(&ImageState::Failed))
+            .retry_error(|e| error_code(e) == Some("InvalidAMIID.NotFound"))
+            .wait(|| self.describe_images(input.clone()))
+    }
+
+    pub fn wait_until_instance_running(
+        &self,
+        input: DescribeInstancesInput,
+        timeout: Duration,
+    ) -> Result<DescribeInstancesOutput> {
+        Waiter::new("instances to be running")
+            .timeout(timeout)
+            .success(|output: &DescribeInstancesOutput| {
+                all(instance_states(output), |s| {
+                    *s == InstanceStateName::Running
+                })
+            })
+            .failure(|output| {
+                instance_states(output).iter().any(|s| {
+                    matches!(
+                        s,
+                        InstanceStateName::ShuttingDown
+                            | InstanceStateName::Terminated
+                            | InstanceStateName::Stopping
+                    )
+                })
+            })
+            .retry_error(|e| error_code(e) == Some("InvalidInstanceID.NotFound"))
+            .wait(|| self.describe_instances(input.clone()))
+    }
+
+    pub fn wait_until_instance_terminated(
+        &self,
+        input: DescribeInstancesInput,
+        timeout: Duration,
+    ) -> Result<DescribeInstancesOutput> {
+        Waiter::new("instances to be terminated")
+            .timeout(timeout)
+            .success(|output: &DescribeInstancesOutput| {
+                all(instance_states(output), |s| {
+                    *s == InstanceStateName::Terminated
+                })
+            })
+            .failure(|output| {
+                instance_states(output)
+                    .iter()
+                    .any(|s| matches!(s, InstanceStateName::Pending | InstanceStateName::Stopping))
             })
-            .map_err(Into::into)
+            // Terminated instances are eventually reported as not found.
+            .success_error(|e| error_code(e) == Some("InvalidInstanceID.NotFound"))
+            .wait(|| self.describe_instances(input.clone()))
+    }
+
+    pub fn wait_until_snapshot_completed(
+        &self,
+        input: DescribeSnapshotsInput,
+        timeout: Duration,
+    ) -> Result<DescribeSnapshotsOutput> {
+        Waiter::new("snapshots to be completed")
+            .timeout(timeout)
+            .success(|output: &DescribeSnapshotsOutput| {
+                all(snapshot_states(output), |s| *s == SnapshotState::Completed)
+            })
+            .failure(|output| snapshot_states(output).contains(&SnapshotState::Error))
+            .wait(|| self.describe_snapshots(input.clone()))
+    }
+
+    pub fn wait_until_volume_available(
+        &self,
+        input: DescribeVolumesInput,
+        timeout: Duration,
+    ) -> Result<DescribeVolumesOutput> {
+        Waiter::new("volumes to be available")
+            .timeout(timeout)
+            .success(|output: &DescribeVolumesOutput| {
+                all(volume_states(output), |s| *s == VolumeState::Available)
+            })
+            .failure(|output| volume_states(output).contains(&VolumeState::Deleted))
+            .wait(|| self.describe_volumes(input.clone()))
+    }
+
+    pub fn wait_until_volume_in_use(
+        &self,
+        input: DescribeVolumesInput,
+        timeout: Duration,
+    ) -> Result<DescribeVolumesOutput> {
+        Waiter::new("volumes to be in use")
+            .timeout(timeout)
+            .success(|output: &DescribeVolumesOutput| {
+                all(volume_states(output), |s| *s == VolumeState::InUse)
+            })
+            .failure(|output| volume_states(output).contains(&VolumeState::Deleted))
+            .wait(|| self.describe_volumes(input.clone()))
+    }
+
+    fn call<I: Serialize, O: DeserializeOwned>(&self, action: &str, input: &I) -> Result<O> {
+        let req = ureq::post(&self.url());
+
+        let mut params = vec![
+            ("Action".into(), action.into()),
+            ("Version".into(), "2016-11-15".into()),
+        ];
+        params.extend(query::to_params(input)?);
+
+        self.send(req, params).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    fn presign_copy_snapshot(
+        &self,
+        source_region: &str,
+        source_snapshot_id: &str,
+    ) -> Result<String> {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("Action", "CopySnapshot")
+            .append_pair("Version", "2016-11-15")
+            .append_pair("DestinationRegion", &self.region)
+            .append_pair("SourceRegion", source_region)
+            .append_pair("SourceSnapshotId", source_snapshot_id)
+            .finish();
+        let url = format!(
+            "https://{}.{}.amazonaws.com/?{}",
+            SERVICE_NAME, source_region, query
+        );
+        let identity = self.credentials.clone().into();
+        let presigned_url = presign_url(
+            &url,
+            &identity,
+            source_region,
+            SERVICE_NAME,
+            Duration::from_secs(3600),
+        )?;
+        Ok(presigned_url)
     }
 
     fn send(&self, mut req: ureq::Request, params: Vec<(String, String)>) -> Result<Response> {
@@ -162,201 +772,5464 @@ impl Api {
     }
 }
 
-trait ToParams {
-    fn to_params(&self, prefix: &str) -> Vec<(String, String)>;
+// Waiters only succeed on a non-empty set of resources.
+fn all<T, F: Fn(&T) -> bool>(items: Vec<T>, f: F) -> bool {
+    !items.is_empty() && items.iter().all(f)
+}
+
+fn error_code(err: &Error) -> Option<&str> {
+    match err {
+        Error::EC2(body) => body.errors.error.first().map(|e| e.code.as_str()),
+        _ => None,
+    }
+}
+
+fn from_base64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<u8>>, D::Error> {
+    let encoded = Option::<String>::deserialize(deserializer)?;
+    encoded
+        .map(|e| BASE64.decode(e.trim()))
+        .transpose()
+        .map_err(de::Error::custom)
+}
+
+// Console output may contain partial multi-byte sequences, which are replaced.
+fn from_base64_lossy<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    let decoded = from_base64(deserializer)?;
+    Ok(decoded.map(|d| String::from_utf8_lossy(&d).into_owned()))
+}
+
+fn image_states(output: &DescribeImagesOutput) -> Vec<ImageState> {
+    output
+        .images
+        .iter()
+        .flat_map(|i| i.items.iter().flatten())
+        .map(|i| i.state.clone().unwrap_or_default())
+        .collect()
+}
+
+fn instance_states(output: &DescribeInstancesOutput) -> Vec<InstanceStateName> {
+    output
+        .reservations
+        .iter()
+        .flat_map(|r| r.items.iter().flatten())
+        .flat_map(|r| r.instances.iter().flat_map(|i| i.items.iter().flatten()))
+        .map(|i| {
+            i.state
+                .as_ref()
+                .and_then(|s| s.name.clone())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn snapshot_states(output: &DescribeSnapshotsOutput) -> Vec<SnapshotState> {
+    output
+        .snapshots
+        .iter()
+        .flat_map(|s| s.items.iter().flatten())
+        .map(|s| s.state.clone().unwrap_or_default())
+        .collect()
+}
+
+fn to_base64<S: Serializer>(
+    value: &Option<String>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(&BASE64.encode(value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn volume_states(output: &DescribeVolumesOutput) -> Vec<VolumeState> {
+    output
+        .volumes
+        .iter()
+        .flat_map(|v| v.items.iter().flatten())
+        .map(|v| v.state.clone().unwrap_or_default())
+        .collect()
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Filter {
     pub name: String,
+    #[serde(rename = "Value")]
     pub values: Vec<String>,
 }
 
-impl ToParams for Filter {
-    fn to_params(&self, prefix: &str) -> Vec<(String, String)> {
-        let mut params = Vec::new();
-        params.push((format!("{}.Name", prefix), self.name.clone()));
-        for (i, val) in self.values.iter().enumerate() {
-            params.push((format!("{}.Value.{}", prefix, i + 1), val.clone()));
+impl Filter {
+    pub fn new(name: &str, values: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            values,
         }
-        params
     }
-}
 
-impl ToParams for Vec<Filter> {
-    fn to_params(&self, prefix: &str) -> Vec<(String, String)> {
-        let mut params = Vec::new();
-        for (i, filter) in self.iter().enumerate() {
-            let filter_prefix = format!("{}.{}", prefix, i + 1);
-            params.extend(filter.to_params(&filter_prefix));
-        }
-        params
+    pub fn tag(key: &str, values: Vec<String>) -> Self {
+        Self::new(&format!("tag:{}", key), values)
     }
 }
 
-impl ToParams for Vec<String> {
-    fn to_params(&self, prefix: &str) -> Vec<(String, String)> {
-        let mut params = Vec::new();
-        for (i, value) in self.iter().enumerate() {
-            let prefix = format!("{}.{}", prefix, i + 1);
-            params.push((prefix, value.clone()));
-        }
-        params
-    }
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AllocateAddressInput {
+    pub address: Option<String>,
+    pub domain: Option<Domain>,
+    pub network_border_group: Option<String>,
+    pub public_ipv4_pool: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AttachVolumeInput {
-    #[serde(rename = "Device")]
-    pub device: String,
-    #[serde(rename = "InstanceId")]
-    pub instance_id: String,
-    #[serde(rename = "VolumeId")]
-    pub volume_id: String,
+impl AllocateAddressInput {
+    pub fn address(mut self, address: &str) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    pub fn network_border_group(mut self, network_border_group: &str) -> Self {
+        self.network_border_group = Some(network_border_group.into());
+        self
+    }
+
+    pub fn public_ipv4_pool(mut self, public_ipv4_pool: &str) -> Self {
+        self.public_ipv4_pool = Some(public_ipv4_pool.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AttachVolumeOutput {
-    #[serde(rename = "associatedResource")]
-    pub associated_resource: Option<String>,
-    #[serde(rename = "attachTime")]
-    pub attach_time: Option<DateTime<Utc>>,
-    #[serde(rename = "deleteOnTermination")]
-    pub delete_on_termination: Option<bool>,
-    #[serde(rename = "device")]
-    pub device: Option<String>,
-    #[serde(rename = "instanceId")]
-    pub instance_id: Option<String>,
-    #[serde(rename = "instanceOwningService")]
-    pub instance_owning_service: Option<Status>,
+pub struct AllocateAddressOutput {
+    #[serde(rename = "allocationId")]
+    pub allocation_id: Option<String>,
+    #[serde(rename = "domain")]
+    pub domain: Option<String>,
+    #[serde(rename = "networkBorderGroup")]
+    pub network_border_group: Option<String>,
+    #[serde(rename = "publicIp")]
+    pub public_ip: Option<String>,
+    #[serde(rename = "publicIpv4Pool")]
+    pub public_ipv4_pool: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Domain {
+    Vpc,
+    Standard,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssociateAddressInput {
+    pub allocation_id: String,
+    pub allow_reassociation: Option<bool>,
+    pub instance_id: Option<String>,
+    pub network_interface_id: Option<String>,
+    pub private_ip_address: Option<String>,
+}
+
+impl AssociateAddressInput {
+    pub fn allocation_id(mut self, allocation_id: &str) -> Self {
+        self.allocation_id = allocation_id.into();
+        self
+    }
+
+    pub fn allow_reassociation(mut self, allow_reassociation: bool) -> Self {
+        self.allow_reassociation = Some(allow_reassociation);
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
+    pub fn network_interface_id(mut self, network_interface_id: &str) -> Self {
+        self.network_interface_id = Some(network_interface_id.into());
+        self
+    }
+
+    pub fn private_ip_address(mut self, private_ip_address: &str) -> Self {
+        self.private_ip_address = Some(private_ip_address.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssociateAddressOutput {
+    #[serde(rename = "associationId")]
+    pub association_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssignPrivateIpAddressesInput {
+    pub allow_reassignment: Option<bool>,
+    pub network_interface_id: String,
+    #[serde(rename = "PrivateIpAddress")]
+    pub private_ip_addresses: Option<Vec<String>>,
+    pub secondary_private_ip_address_count: Option<u32>,
+}
+
+impl AssignPrivateIpAddressesInput {
+    pub fn allow_reassignment(mut self, allow_reassignment: bool) -> Self {
+        self.allow_reassignment = Some(allow_reassignment);
+        self
+    }
+
+    pub fn network_interface_id(mut self, network_interface_id: &str) -> Self {
+        self.network_interface_id = network_interface_id.into();
+        self
+    }
+
+    pub fn private_ip_addresses(mut self, private_ip_addresses: Vec<String>) -> Self {
+        self.private_ip_addresses = Some(private_ip_addresses);
+        self
+    }
+
+    pub fn secondary_private_ip_address_count(mut self, count: u32) -> Self {
+        self.secondary_private_ip_address_count = Some(count);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssignPrivateIpAddressesOutput {
+    #[serde(rename = "assignedPrivateIpAddressesSet")]
+    pub assigned_private_ip_addresses: Option<AssignedPrivateIpAddressSet>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssignedPrivateIpAddressSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<AssignedPrivateIpAddress>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssignedPrivateIpAddress {
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachNetworkInterfaceInput {
+    pub device_index: u32,
+    pub instance_id: String,
+    pub network_card_index: Option<u32>,
+    pub network_interface_id: String,
+}
+
+impl AttachNetworkInterfaceInput {
+    pub fn device_index(mut self, device_index: u32) -> Self {
+        self.device_index = device_index;
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    pub fn network_card_index(mut self, network_card_index: u32) -> Self {
+        self.network_card_index = Some(network_card_index);
+        self
+    }
+
+    pub fn network_interface_id(mut self, network_interface_id: &str) -> Self {
+        self.network_interface_id = network_interface_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachNetworkInterfaceOutput {
+    #[serde(rename = "attachmentId")]
+    pub attachment_id: Option<String>,
+    #[serde(rename = "networkCardIndex")]
+    pub network_card_index: Option<u32>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachVolumeInput {
+    #[serde(rename = "Device")]
+    pub device: String,
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "VolumeId")]
+    pub volume_id: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachVolumeOutput {
+    #[serde(rename = "associatedResource")]
+    pub associated_resource: Option<String>,
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "device")]
+    pub device: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceOwningService")]
+    pub instance_owning_service: Option<String>,
     #[serde(rename = "requestId")]
     pub request_id: Option<String>,
     #[serde(rename = "status")]
-    pub status: Option<Status>,
+    pub status: Option<AttachmentStatus>,
     #[serde(rename = "volumeId")]
     pub volume_id: Option<String>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct DescribeVolumesInput {
-    pub filters: Option<Vec<Filter>>,
-    pub max_results: Option<u32>,
-    pub next_token: Option<String>,
-    pub volume_ids: Option<Vec<String>>,
+pub struct AuthorizeSecurityGroupEgressInput {
+    pub group_id: String,
+    pub ip_permissions: Vec<IpPermission>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl AuthorizeSecurityGroupEgressInput {
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = group_id.into();
+        self
+    }
+
+    pub fn ip_permissions(mut self, ip_permissions: Vec<IpPermission>) -> Self {
+        self.ip_permissions = ip_permissions;
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthorizeSecurityGroupEgressOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+    #[serde(rename = "securityGroupRuleSet")]
+    pub security_group_rules: Option<SecurityGroupRuleSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthorizeSecurityGroupIngressInput {
+    pub group_id: String,
+    pub ip_permissions: Vec<IpPermission>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl AuthorizeSecurityGroupIngressInput {
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = group_id.into();
+        self
+    }
+
+    pub fn ip_permissions(mut self, ip_permissions: Vec<IpPermission>) -> Self {
+        self.ip_permissions = ip_permissions;
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthorizeSecurityGroupIngressOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+    #[serde(rename = "securityGroupRuleSet")]
+    pub security_group_rules: Option<SecurityGroupRuleSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecurityGroupRuleSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<SecurityGroupRule>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecurityGroupRule {
+    #[serde(rename = "cidrIpv4")]
+    pub cidr_ipv4: Option<String>,
+    #[serde(rename = "cidrIpv6")]
+    pub cidr_ipv6: Option<String>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "fromPort")]
+    pub from_port: Option<i32>,
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "groupOwnerId")]
+    pub group_owner_id: Option<String>,
+    #[serde(rename = "ipProtocol")]
+    pub ip_protocol: Option<String>,
+    #[serde(rename = "isEgress")]
+    pub is_egress: Option<bool>,
+    #[serde(rename = "prefixListId")]
+    pub prefix_list_id: Option<String>,
+    #[serde(rename = "referencedGroupInfo")]
+    pub referenced_group_info: Option<ReferencedSecurityGroup>,
+    #[serde(rename = "securityGroupRuleId")]
+    pub security_group_rule_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "toPort")]
+    pub to_port: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReferencedSecurityGroup {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "peeringStatus")]
+    pub peering_status: Option<String>,
+    #[serde(rename = "userId")]
+    pub user_id: Option<String>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+    #[serde(rename = "vpcPeeringConnectionId")]
+    pub vpc_peering_connection_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancelSpotInstanceRequestsInput {
+    #[serde(rename = "SpotInstanceRequestId")]
+    pub spot_instance_request_ids: Vec<String>,
+}
+
+impl CancelSpotInstanceRequestsInput {
+    pub fn spot_instance_request_ids(mut self, spot_instance_request_ids: Vec<String>) -> Self {
+        self.spot_instance_request_ids = spot_instance_request_ids;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancelSpotInstanceRequestsOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "spotInstanceRequestSet")]
+    pub cancelled_spot_instance_requests: Option<CancelledSpotInstanceRequestSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancelledSpotInstanceRequestSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<CancelledSpotInstanceRequest>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancelledSpotInstanceRequest {
+    #[serde(rename = "spotInstanceRequestId")]
+    pub spot_instance_request_id: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<CancelSpotInstanceRequestState>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CancelSpotInstanceRequestState {
+    Active,
+    Open,
+    Closed,
+    Cancelled,
+    Completed,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopyImageInput {
+    pub client_token: Option<String>,
+    pub copy_image_tags: Option<bool>,
+    pub description: Option<String>,
+    pub encrypted: Option<bool>,
+    pub kms_key_id: Option<String>,
+    pub name: String,
+    pub source_image_id: String,
+    pub source_region: String,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl CopyImageInput {
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn copy_image_tags(mut self, copy_image_tags: bool) -> Self {
+        self.copy_image_tags = Some(copy_image_tags);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn encrypted(mut self, encrypted: bool) -> Self {
+        self.encrypted = Some(encrypted);
+        self
+    }
+
+    pub fn kms_key_id(mut self, kms_key_id: &str) -> Self {
+        self.kms_key_id = Some(kms_key_id.into());
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn source_image_id(mut self, source_image_id: &str) -> Self {
+        self.source_image_id = source_image_id.into();
+        self
+    }
+
+    pub fn source_region(mut self, source_region: &str) -> Self {
+        self.source_region = source_region.into();
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopyImageOutput {
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopySnapshotInput {
+    pub description: Option<String>,
+    pub destination_region: Option<String>,
+    pub encrypted: Option<bool>,
+    pub kms_key_id: Option<String>,
+    pub presigned_url: Option<String>,
+    pub source_region: String,
+    pub source_snapshot_id: String,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl CopySnapshotInput {
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn destination_region(mut self, destination_region: &str) -> Self {
+        self.destination_region = Some(destination_region.into());
+        self
+    }
+
+    pub fn encrypted(mut self, encrypted: bool) -> Self {
+        self.encrypted = Some(encrypted);
+        self
+    }
+
+    pub fn kms_key_id(mut self, kms_key_id: &str) -> Self {
+        self.kms_key_id = Some(kms_key_id.into());
+        self
+    }
+
+    pub fn presigned_url(mut self, presigned_url: &str) -> Self {
+        self.presigned_url = Some(presigned_url.into());
+        self
+    }
+
+    pub fn source_region(mut self, source_region: &str) -> Self {
+        self.source_region = source_region.into();
+        self
+    }
+
+    pub fn source_snapshot_id(mut self, source_snapshot_id: &str) -> Self {
+        self.source_snapshot_id = source_snapshot_id.into();
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopySnapshotOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "snapshotId")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateImageInput {
+    #[serde(rename = "BlockDeviceMapping")]
+    pub block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    pub description: Option<String>,
+    pub instance_id: String,
+    pub name: String,
+    pub no_reboot: Option<bool>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl CreateImageInput {
+    pub fn block_device_mappings(mut self, block_device_mappings: Vec<BlockDeviceMapping>) -> Self {
+        self.block_device_mappings = Some(block_device_mappings);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn no_reboot(mut self, no_reboot: bool) -> Self {
+        self.no_reboot = Some(no_reboot);
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateImageOutput {
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateLaunchTemplateInput {
+    pub client_token: Option<String>,
+    pub launch_template_data: LaunchTemplateData,
+    pub launch_template_name: String,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub version_description: Option<String>,
+}
+
+impl CreateLaunchTemplateInput {
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn launch_template_data(mut self, launch_template_data: LaunchTemplateData) -> Self {
+        self.launch_template_data = launch_template_data;
+        self
+    }
+
+    pub fn launch_template_name(mut self, launch_template_name: &str) -> Self {
+        self.launch_template_name = launch_template_name.into();
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn version_description(mut self, version_description: &str) -> Self {
+        self.version_description = Some(version_description.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateLaunchTemplateOutput {
+    #[serde(rename = "launchTemplate")]
+    pub launch_template: Option<LaunchTemplate>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplate {
+    #[serde(rename = "createTime")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<String>,
+    #[serde(rename = "defaultVersionNumber")]
+    pub default_version_number: Option<u64>,
+    #[serde(rename = "latestVersionNumber")]
+    pub latest_version_number: Option<u64>,
+    #[serde(rename = "launchTemplateId")]
+    pub launch_template_id: Option<String>,
+    #[serde(rename = "launchTemplateName")]
+    pub launch_template_name: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateData {
+    #[serde(rename = "BlockDeviceMapping")]
+    pub block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    pub disable_api_termination: Option<bool>,
+    pub ebs_optimized: Option<bool>,
+    pub iam_instance_profile: Option<IamInstanceProfileSpecification>,
+    pub image_id: Option<String>,
+    pub instance_initiated_shutdown_behavior: Option<String>,
+    pub instance_type: Option<String>,
+    pub key_name: Option<String>,
+    pub metadata_options: Option<InstanceMetadataOptionsRequest>,
+    #[serde(rename = "NetworkInterface")]
+    pub network_interfaces: Option<Vec<InstanceNetworkInterfaceSpecification>>,
+    pub placement: Option<Placement>,
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_ids: Option<Vec<String>>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    #[serde(serialize_with = "to_base64")]
+    pub user_data: Option<String>,
+}
+
+impl LaunchTemplateData {
+    pub fn block_device_mappings(mut self, block_device_mappings: Vec<BlockDeviceMapping>) -> Self {
+        self.block_device_mappings = Some(block_device_mappings);
+        self
+    }
+
+    pub fn disable_api_termination(mut self, disable_api_termination: bool) -> Self {
+        self.disable_api_termination = Some(disable_api_termination);
+        self
+    }
+
+    pub fn ebs_optimized(mut self, ebs_optimized: bool) -> Self {
+        self.ebs_optimized = Some(ebs_optimized);
+        self
+    }
+
+    pub fn iam_instance_profile(
+        mut self,
+        iam_instance_profile: IamInstanceProfileSpecification,
+    ) -> Self {
+        self.iam_instance_profile = Some(iam_instance_profile);
+        self
+    }
+
+    pub fn image_id(mut self, image_id: &str) -> Self {
+        self.image_id = Some(image_id.into());
+        self
+    }
+
+    pub fn instance_initiated_shutdown_behavior(mut self, behavior: &str) -> Self {
+        self.instance_initiated_shutdown_behavior = Some(behavior.into());
+        self
+    }
+
+    pub fn instance_type(mut self, instance_type: &str) -> Self {
+        self.instance_type = Some(instance_type.into());
+        self
+    }
+
+    pub fn key_name(mut self, key_name: &str) -> Self {
+        self.key_name = Some(key_name.into());
+        self
+    }
+
+    pub fn metadata_options(mut self, metadata_options: InstanceMetadataOptionsRequest) -> Self {
+        self.metadata_options = Some(metadata_options);
+        self
+    }
+
+    pub fn network_interfaces(
+        mut self,
+        network_interfaces: Vec<InstanceNetworkInterfaceSpecification>,
+    ) -> Self {
+        self.network_interfaces = Some(network_interfaces);
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    pub fn security_group_ids(mut self, security_group_ids: Vec<String>) -> Self {
+        self.security_group_ids = Some(security_group_ids);
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    // Plain text, base64 encoded when sent.
+    pub fn user_data(mut self, user_data: &str) -> Self {
+        self.user_data = Some(user_data.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateLaunchTemplateVersionInput {
+    pub client_token: Option<String>,
+    pub launch_template_data: LaunchTemplateData,
+    pub launch_template_id: Option<String>,
+    pub launch_template_name: Option<String>,
+    pub source_version: Option<String>,
+    pub version_description: Option<String>,
+}
+
+impl CreateLaunchTemplateVersionInput {
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn launch_template_data(mut self, launch_template_data: LaunchTemplateData) -> Self {
+        self.launch_template_data = launch_template_data;
+        self
+    }
+
+    pub fn launch_template_id(mut self, launch_template_id: &str) -> Self {
+        self.launch_template_id = Some(launch_template_id.into());
+        self
+    }
+
+    pub fn launch_template_name(mut self, launch_template_name: &str) -> Self {
+        self.launch_template_name = Some(launch_template_name.into());
+        self
+    }
+
+    // Data not given in launch_template_data is inherited from this version.
+    pub fn source_version(mut self, source_version: &str) -> Self {
+        self.source_version = Some(source_version.into());
+        self
+    }
+
+    pub fn version_description(mut self, version_description: &str) -> Self {
+        self.version_description = Some(version_description.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateLaunchTemplateVersionOutput {
+    #[serde(rename = "launchTemplateVersion")]
+    pub launch_template_version: Option<LaunchTemplateVersion>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateVersion {
+    #[serde(rename = "createTime")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<String>,
+    #[serde(rename = "defaultVersion")]
+    pub default_version: Option<bool>,
+    #[serde(rename = "launchTemplateData")]
+    pub launch_template_data: Option<ResponseLaunchTemplateData>,
+    #[serde(rename = "launchTemplateId")]
+    pub launch_template_id: Option<String>,
+    #[serde(rename = "launchTemplateName")]
+    pub launch_template_name: Option<String>,
+    #[serde(rename = "versionDescription")]
+    pub version_description: Option<String>,
+    #[serde(rename = "versionNumber")]
+    pub version_number: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ResponseLaunchTemplateData {
+    #[serde(rename = "blockDeviceMappingSet")]
+    pub block_device_mappings: Option<BlockDeviceMappingSet>,
+    #[serde(rename = "disableApiTermination")]
+    pub disable_api_termination: Option<bool>,
+    #[serde(rename = "ebsOptimized")]
+    pub ebs_optimized: Option<bool>,
+    #[serde(rename = "iamInstanceProfile")]
+    pub iam_instance_profile: Option<IamInstanceProfileSpecification>,
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "instanceInitiatedShutdownBehavior")]
+    pub instance_initiated_shutdown_behavior: Option<String>,
+    #[serde(rename = "instanceType")]
+    pub instance_type: Option<String>,
+    #[serde(rename = "keyName")]
+    pub key_name: Option<String>,
+    #[serde(rename = "metadataOptions")]
+    pub metadata_options: Option<InstanceMetadataOptions>,
+    #[serde(rename = "networkInterfaceSet")]
+    pub network_interfaces: Option<LaunchTemplateNetworkInterfaceSet>,
+    #[serde(rename = "placement")]
+    pub placement: Option<Placement>,
+    #[serde(rename = "securityGroupIdSet")]
+    pub security_group_ids: Option<ValueStringSet>,
+    #[serde(rename = "tagSpecificationSet")]
+    pub tag_specifications: Option<LaunchTemplateTagSpecificationSet>,
+    // Base64 encoded, as stored in the template.
+    #[serde(rename = "userData")]
+    pub user_data: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateNetworkInterfaceSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<LaunchTemplateNetworkInterface>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateNetworkInterface {
+    #[serde(rename = "associatePublicIpAddress")]
+    pub associate_public_ip_address: Option<bool>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "deviceIndex")]
+    pub device_index: Option<u32>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdStringSet>,
+    #[serde(rename = "networkCardIndex")]
+    pub network_card_index: Option<u32>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+    #[serde(rename = "secondaryPrivateIpAddressCount")]
+    pub secondary_private_ip_address_count: Option<u32>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GroupIdStringSet {
+    #[serde(rename = "groupId")]
+    pub items: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueStringSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateTagSpecificationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<LaunchTemplateTagSpecification>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateTagSpecification {
+    #[serde(rename = "resourceType")]
+    pub resource_type: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateNetworkInterfaceInput {
+    pub client_token: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "SecurityGroupId")]
+    pub groups: Option<Vec<String>>,
+    pub interface_type: Option<String>,
+    pub private_ip_address: Option<String>,
+    pub secondary_private_ip_address_count: Option<u32>,
+    pub subnet_id: String,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+}
+
+impl CreateNetworkInterfaceInput {
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    pub fn interface_type(mut self, interface_type: &str) -> Self {
+        self.interface_type = Some(interface_type.into());
+        self
+    }
+
+    pub fn private_ip_address(mut self, private_ip_address: &str) -> Self {
+        self.private_ip_address = Some(private_ip_address.into());
+        self
+    }
+
+    pub fn secondary_private_ip_address_count(mut self, count: u32) -> Self {
+        self.secondary_private_ip_address_count = Some(count);
+        self
+    }
+
+    pub fn subnet_id(mut self, subnet_id: &str) -> Self {
+        self.subnet_id = subnet_id.into();
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateNetworkInterfaceOutput {
+    #[serde(rename = "clientToken")]
+    pub client_token: Option<String>,
+    #[serde(rename = "networkInterface")]
+    pub network_interface: Option<NetworkInterface>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateSecurityGroupInput {
+    #[serde(rename = "GroupDescription")]
+    pub description: String,
+    pub group_name: String,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub vpc_id: Option<String>,
+}
+
+impl CreateSecurityGroupInput {
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn group_name(mut self, group_name: &str) -> Self {
+        self.group_name = group_name.into();
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn vpc_id(mut self, vpc_id: &str) -> Self {
+        self.vpc_id = Some(vpc_id.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateSecurityGroupOutput {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+    #[serde(rename = "securityGroupArn")]
+    pub security_group_arn: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateSnapshotInput {
+    pub description: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub volume_id: String,
+}
+
+impl CreateSnapshotInput {
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn volume_id(mut self, volume_id: &str) -> Self {
+        self.volume_id = volume_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateSnapshotOutput {
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "encrypted")]
+    pub encrypted: Option<bool>,
+    #[serde(rename = "kmsKeyId")]
+    pub kms_key_id: Option<String>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "progress")]
+    pub progress: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "snapshotId")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "startTime")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(rename = "status")]
+    pub state: Option<SnapshotState>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+    #[serde(rename = "volumeSize")]
+    pub volume_size: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTagsInput {
+    #[serde(rename = "ResourceId")]
+    pub resource_ids: Vec<String>,
+    #[serde(rename = "Tag")]
+    pub tags: Vec<Tag>,
+}
+
+impl CreateTagsInput {
+    pub fn resource_ids(mut self, resource_ids: Vec<String>) -> Self {
+        self.resource_ids = resource_ids;
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTagsOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateVolumeInput {
+    pub availability_zone: String,
+    pub client_token: Option<String>,
+    pub encrypted: Option<bool>,
+    pub iops: Option<u32>,
+    pub kms_key_id: Option<String>,
+    pub multi_attach_enabled: Option<bool>,
+    pub size: Option<u32>,
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub throughput: Option<u32>,
+    pub volume_type: Option<VolumeType>,
+}
+
+impl CreateVolumeInput {
+    pub fn availability_zone(mut self, availability_zone: &str) -> Self {
+        self.availability_zone = availability_zone.into();
+        self
+    }
+
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn encrypted(mut self, encrypted: bool) -> Self {
+        self.encrypted = Some(encrypted);
+        self
+    }
+
+    pub fn iops(mut self, iops: u32) -> Self {
+        self.iops = Some(iops);
+        self
+    }
+
+    pub fn kms_key_id(mut self, kms_key_id: &str) -> Self {
+        self.kms_key_id = Some(kms_key_id.into());
+        self
+    }
+
+    pub fn multi_attach_enabled(mut self, multi_attach_enabled: bool) -> Self {
+        self.multi_attach_enabled = Some(multi_attach_enabled);
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn snapshot_id(mut self, snapshot_id: &str) -> Self {
+        self.snapshot_id = Some(snapshot_id.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn throughput(mut self, throughput: u32) -> Self {
+        self.throughput = Some(throughput);
+        self
+    }
+
+    pub fn volume_type(mut self, volume_type: VolumeType) -> Self {
+        self.volume_type = Some(volume_type);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateVolumeOutput {
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "createTime")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "encrypted")]
+    pub encrypted: Option<bool>,
+    #[serde(rename = "iops")]
+    pub iops: Option<u32>,
+    #[serde(rename = "kmsKeyId")]
+    pub kms_key_id: Option<String>,
+    #[serde(rename = "multiAttachEnabled")]
+    pub multi_attach_enabled: Option<bool>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "size")]
+    pub size: Option<u32>,
+    #[serde(rename = "snapshotId")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "status")]
+    pub state: Option<VolumeState>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "throughput")]
+    pub throughput: Option<u32>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+    #[serde(rename = "volumeType")]
+    pub volume_type: Option<VolumeType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteNetworkInterfaceInput {
+    pub network_interface_id: String,
+}
+
+impl DeleteNetworkInterfaceInput {
+    pub fn network_interface_id(mut self, network_interface_id: &str) -> Self {
+        self.network_interface_id = network_interface_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteNetworkInterfaceOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteSecurityGroupInput {
+    pub group_id: String,
+}
+
+impl DeleteSecurityGroupInput {
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = group_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteSecurityGroupOutput {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteSnapshotInput {
+    pub snapshot_id: String,
+}
+
+impl DeleteSnapshotInput {
+    pub fn snapshot_id(mut self, snapshot_id: &str) -> Self {
+        self.snapshot_id = snapshot_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteSnapshotOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteTagsInput {
+    #[serde(rename = "ResourceId")]
+    pub resource_ids: Vec<String>,
+    #[serde(rename = "Tag")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl DeleteTagsInput {
+    pub fn resource_ids(mut self, resource_ids: Vec<String>) -> Self {
+        self.resource_ids = resource_ids;
+        self
+    }
+
+    // A tag without a value deletes the key regardless of its value, and
+    // no tags at all deletes every tag on the resources.
+    pub fn tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteTagsOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteVolumeInput {
+    pub volume_id: String,
+}
+
+impl DeleteVolumeInput {
+    pub fn volume_id(mut self, volume_id: &str) -> Self {
+        self.volume_id = volume_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteVolumeOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeregisterImageInput {
+    pub image_id: String,
+}
+
+impl DeregisterImageInput {
+    pub fn image_id(mut self, image_id: &str) -> Self {
+        self.image_id = image_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeregisterImageOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeAddressesInput {
+    #[serde(rename = "AllocationId")]
+    pub allocation_ids: Option<Vec<String>>,
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "PublicIp")]
+    pub public_ips: Option<Vec<String>>,
+}
+
+impl DescribeAddressesInput {
+    pub fn allocation_ids(mut self, allocation_ids: Vec<String>) -> Self {
+        self.allocation_ids = Some(allocation_ids);
+        self
+    }
+
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn public_ips(mut self, public_ips: Vec<String>) -> Self {
+        self.public_ips = Some(public_ips);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeAddressesOutput {
+    #[serde(rename = "addressesSet")]
+    pub addresses: Option<AddressSet>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AddressSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Address>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Address {
+    #[serde(rename = "allocationId")]
+    pub allocation_id: Option<String>,
+    #[serde(rename = "associationId")]
+    pub association_id: Option<String>,
+    #[serde(rename = "carrierIp")]
+    pub carrier_ip: Option<String>,
+    #[serde(rename = "customerOwnedIp")]
+    pub customer_owned_ip: Option<String>,
+    #[serde(rename = "domain")]
+    pub domain: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "networkBorderGroup")]
+    pub network_border_group: Option<String>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "networkInterfaceOwnerId")]
+    pub network_interface_owner_id: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+    #[serde(rename = "publicIp")]
+    pub public_ip: Option<String>,
+    #[serde(rename = "publicIpv4Pool")]
+    pub public_ipv4_pool: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeAvailabilityZonesInput {
+    pub all_availability_zones: Option<bool>,
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "ZoneId")]
+    pub zone_ids: Option<Vec<String>>,
+    #[serde(rename = "ZoneName")]
+    pub zone_names: Option<Vec<String>>,
+}
+
+impl DescribeAvailabilityZonesInput {
+    pub fn all_availability_zones(mut self, all_availability_zones: bool) -> Self {
+        self.all_availability_zones = Some(all_availability_zones);
+        self
+    }
+
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn zone_ids(mut self, zone_ids: Vec<String>) -> Self {
+        self.zone_ids = Some(zone_ids);
+        self
+    }
+
+    pub fn zone_names(mut self, zone_names: Vec<String>) -> Self {
+        self.zone_names = Some(zone_names);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeAvailabilityZonesOutput {
+    #[serde(rename = "availabilityZoneInfo")]
+    pub availability_zones: Option<AvailabilityZoneSet>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AvailabilityZoneSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<AvailabilityZone>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AvailabilityZone {
+    #[serde(rename = "groupName")]
+    pub group_name: Option<String>,
+    #[serde(rename = "messageSet")]
+    pub messages: Option<AvailabilityZoneMessageSet>,
+    #[serde(rename = "networkBorderGroup")]
+    pub network_border_group: Option<String>,
+    #[serde(rename = "optInStatus")]
+    pub opt_in_status: Option<String>,
+    #[serde(rename = "parentZoneId")]
+    pub parent_zone_id: Option<String>,
+    #[serde(rename = "parentZoneName")]
+    pub parent_zone_name: Option<String>,
+    #[serde(rename = "regionName")]
+    pub region_name: Option<String>,
+    #[serde(rename = "zoneState")]
+    pub state: Option<AvailabilityZoneState>,
+    #[serde(rename = "zoneId")]
+    pub zone_id: Option<String>,
+    #[serde(rename = "zoneName")]
+    pub zone_name: Option<String>,
+    #[serde(rename = "zoneType")]
+    pub zone_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AvailabilityZoneMessageSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<AvailabilityZoneMessage>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AvailabilityZoneMessage {
+    #[serde(rename = "message")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AvailabilityZoneState {
+    Available,
+    Information,
+    Impaired,
+    Unavailable,
+    Constrained,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeImagesInput {
+    #[serde(rename = "ExecutableBy")]
+    pub executable_users: Option<Vec<String>>,
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "ImageId")]
+    pub image_ids: Option<Vec<String>>,
+    pub include_deprecated: Option<bool>,
+    pub include_disabled: Option<bool>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "Owner")]
+    pub owners: Option<Vec<String>>,
+}
+
+impl DescribeImagesInput {
+    pub fn executable_users(mut self, executable_users: Vec<String>) -> Self {
+        self.executable_users = Some(executable_users);
+        self
+    }
+
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn image_ids(mut self, image_ids: Vec<String>) -> Self {
+        self.image_ids = Some(image_ids);
+        self
+    }
+
+    pub fn include_deprecated(mut self, include_deprecated: bool) -> Self {
+        self.include_deprecated = Some(include_deprecated);
+        self
+    }
+
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    // Account ids, or "self", "amazon" and "aws-marketplace".
+    pub fn owners(mut self, owners: Vec<String>) -> Self {
+        self.owners = Some(owners);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeImagesOutput {
+    #[serde(rename = "imagesSet")]
+    pub images: Option<ImageSet>,
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+pub struct DescribeImagesPaginator<'a> {
+    api: &'a Api,
+    input: DescribeImagesInput,
+    done: bool,
+}
+
+impl Iterator for DescribeImagesPaginator<'_> {
+    type Item = Result<DescribeImagesOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_images(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ImageSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Image>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Image {
+    #[serde(rename = "architecture")]
+    pub architecture: Option<String>,
+    #[serde(rename = "blockDeviceMapping")]
+    pub block_device_mappings: Option<BlockDeviceMappingSet>,
+    #[serde(rename = "bootMode")]
+    pub boot_mode: Option<String>,
+    #[serde(rename = "creationDate")]
+    pub creation_date: Option<DateTime<Utc>>,
+    #[serde(rename = "deprecationTime")]
+    pub deprecation_time: Option<DateTime<Utc>>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "enaSupport")]
+    pub ena_support: Option<bool>,
+    #[serde(rename = "hypervisor")]
+    pub hypervisor: Option<String>,
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "imageLocation")]
+    pub image_location: Option<String>,
+    #[serde(rename = "imageOwnerAlias")]
+    pub image_owner_alias: Option<String>,
+    #[serde(rename = "imageType")]
+    pub image_type: Option<String>,
+    #[serde(rename = "imdsSupport")]
+    pub imds_support: Option<String>,
+    #[serde(rename = "isPublic")]
+    pub is_public: Option<bool>,
+    #[serde(rename = "kernelId")]
+    pub kernel_id: Option<String>,
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+    #[serde(rename = "imageOwnerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "platform")]
+    pub platform: Option<String>,
+    #[serde(rename = "platformDetails")]
+    pub platform_details: Option<String>,
+    #[serde(rename = "ramdiskId")]
+    pub ramdisk_id: Option<String>,
+    #[serde(rename = "rootDeviceName")]
+    pub root_device_name: Option<String>,
+    #[serde(rename = "rootDeviceType")]
+    pub root_device_type: Option<String>,
+    #[serde(rename = "sriovNetSupport")]
+    pub sriov_net_support: Option<String>,
+    #[serde(rename = "imageState")]
+    pub state: Option<ImageState>,
+    #[serde(rename = "stateReason")]
+    pub state_reason: Option<StateReason>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "tpmSupport")]
+    pub tpm_support: Option<String>,
+    #[serde(rename = "usageOperation")]
+    pub usage_operation: Option<String>,
+    #[serde(rename = "virtualizationType")]
+    pub virtualization_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageState {
+    Pending,
+    Available,
+    Invalid,
+    Deregistered,
+    Transient,
+    Failed,
+    Error,
+    Disabled,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BlockDeviceMappingSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<BlockDeviceMapping>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeInstanceAttributeInput {
+    pub attribute: String,
+    pub instance_id: String,
+}
+
+impl DescribeInstanceAttributeInput {
+    // For example "instanceType", "userData", "disableApiTermination" or
+    // "sourceDestCheck".
+    pub fn attribute(mut self, attribute: &str) -> Self {
+        self.attribute = attribute.into();
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+}
+
+// Only the requested attribute is set.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeInstanceAttributeOutput {
+    #[serde(rename = "blockDeviceMapping")]
+    pub block_device_mappings: Option<InstanceBlockDeviceMappingSet>,
+    #[serde(rename = "disableApiStop")]
+    pub disable_api_stop: Option<AttributeBooleanValue>,
+    #[serde(rename = "disableApiTermination")]
+    pub disable_api_termination: Option<AttributeBooleanValue>,
+    #[serde(rename = "ebsOptimized")]
+    pub ebs_optimized: Option<AttributeBooleanValue>,
+    #[serde(rename = "enaSupport")]
+    pub ena_support: Option<AttributeBooleanValue>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceInitiatedShutdownBehavior")]
+    pub instance_initiated_shutdown_behavior: Option<AttributeValue>,
+    #[serde(rename = "instanceType")]
+    pub instance_type: Option<AttributeValue>,
+    #[serde(rename = "kernel")]
+    pub kernel_id: Option<AttributeValue>,
+    #[serde(rename = "ramdisk")]
+    pub ramdisk_id: Option<AttributeValue>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "rootDeviceName")]
+    pub root_device_name: Option<AttributeValue>,
+    #[serde(rename = "sourceDestCheck")]
+    pub source_dest_check: Option<AttributeBooleanValue>,
+    #[serde(rename = "sriovNetSupport")]
+    pub sriov_net_support: Option<AttributeValue>,
+    // Base64 encoded, use user_data() for the decoded value.
+    #[serde(rename = "userData")]
+    pub user_data: Option<AttributeValue>,
+}
+
+impl DescribeInstanceAttributeOutput {
+    pub fn user_data(&self) -> Option<String> {
+        let value = self.user_data.as_ref()?.value.as_ref()?;
+        let decoded = BASE64.decode(value).ok()?;
+        String::from_utf8(decoded).ok()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttributeBooleanValue {
+    #[serde(rename = "value")]
+    pub value: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttributeValue {
+    #[serde(rename = "value")]
+    pub value: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeInstancesInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "InstanceId")]
+    pub instance_ids: Option<Vec<String>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+}
+
+impl DescribeInstancesInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn instance_ids(mut self, instance_ids: Vec<String>) -> Self {
+        self.instance_ids = Some(instance_ids);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeInstancesOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "reservationSet")]
+    pub reservations: Option<ReservationSet>,
+}
+
+pub struct DescribeInstancesPaginator<'a> {
+    api: &'a Api,
+    input: DescribeInstancesInput,
+    done: bool,
+}
+
+impl Iterator for DescribeInstancesPaginator<'_> {
+    type Item = Result<DescribeInstancesOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_instances(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReservationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Reservation>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Reservation {
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "instancesSet")]
+    pub instances: Option<InstanceSet>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "requesterId")]
+    pub requester_id: Option<String>,
+    #[serde(rename = "reservationId")]
+    pub reservation_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Instance>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Instance {
+    #[serde(rename = "amiLaunchIndex")]
+    pub ami_launch_index: Option<u32>,
+    #[serde(rename = "architecture")]
+    pub architecture: Option<String>,
+    #[serde(rename = "blockDeviceMapping")]
+    pub block_device_mappings: Option<InstanceBlockDeviceMappingSet>,
+    #[serde(rename = "clientToken")]
+    pub client_token: Option<String>,
+    #[serde(rename = "ebsOptimized")]
+    pub ebs_optimized: Option<bool>,
+    #[serde(rename = "enaSupport")]
+    pub ena_support: Option<bool>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "hypervisor")]
+    pub hypervisor: Option<String>,
+    #[serde(rename = "iamInstanceProfile")]
+    pub iam_instance_profile: Option<IamInstanceProfile>,
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceLifecycle")]
+    pub instance_lifecycle: Option<String>,
+    #[serde(rename = "instanceState")]
+    pub state: Option<InstanceState>,
+    #[serde(rename = "instanceType")]
+    pub instance_type: Option<String>,
+    #[serde(rename = "ipAddress")]
+    pub public_ip_address: Option<String>,
+    #[serde(rename = "keyName")]
+    pub key_name: Option<String>,
+    #[serde(rename = "launchTime")]
+    pub launch_time: Option<DateTime<Utc>>,
+    #[serde(rename = "metadataOptions")]
+    pub metadata_options: Option<InstanceMetadataOptions>,
+    #[serde(rename = "monitoring")]
+    pub monitoring: Option<Monitoring>,
+    #[serde(rename = "networkInterfaceSet")]
+    pub network_interfaces: Option<InstanceNetworkInterfaceSet>,
+    #[serde(rename = "placement")]
+    pub placement: Option<Placement>,
+    #[serde(rename = "platform")]
+    pub platform: Option<String>,
+    #[serde(rename = "platformDetails")]
+    pub platform_details: Option<String>,
+    #[serde(rename = "privateDnsName")]
+    pub private_dns_name: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+    #[serde(rename = "dnsName")]
+    pub public_dns_name: Option<String>,
+    #[serde(rename = "rootDeviceName")]
+    pub root_device_name: Option<String>,
+    #[serde(rename = "rootDeviceType")]
+    pub root_device_type: Option<String>,
+    #[serde(rename = "sourceDestCheck")]
+    pub source_dest_check: Option<bool>,
+    #[serde(rename = "spotInstanceRequestId")]
+    pub spot_instance_request_id: Option<String>,
+    #[serde(rename = "reason")]
+    pub state_transition_reason: Option<String>,
+    #[serde(rename = "stateReason")]
+    pub state_reason: Option<StateReason>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "virtualizationType")]
+    pub virtualization_type: Option<String>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceState {
+    #[serde(rename = "code")]
+    pub code: Option<u16>,
+    #[serde(rename = "name")]
+    pub name: Option<InstanceStateName>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InstanceStateName {
+    Pending,
+    Running,
+    ShuttingDown,
+    Terminated,
+    Stopping,
+    Stopped,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StateReason {
+    #[serde(rename = "code")]
+    pub code: Option<String>,
+    #[serde(rename = "message")]
+    pub message: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceBlockDeviceMappingSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<InstanceBlockDeviceMapping>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceBlockDeviceMapping {
+    #[serde(rename = "deviceName")]
+    pub device_name: Option<String>,
+    #[serde(rename = "ebs")]
+    pub ebs: Option<EbsInstanceBlockDevice>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EbsInstanceBlockDevice {
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "status")]
+    pub status: Option<AttachmentStatus>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GroupIdentifierSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<GroupIdentifier>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GroupIdentifier {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "groupName")]
+    pub group_name: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IamInstanceProfile {
+    #[serde(rename = "arn")]
+    pub arn: Option<String>,
+    #[serde(rename = "id")]
+    pub id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceMetadataOptions {
+    #[serde(rename = "httpEndpoint")]
+    pub http_endpoint: Option<String>,
+    #[serde(rename = "httpProtocolIpv6")]
+    pub http_protocol_ipv6: Option<String>,
+    #[serde(rename = "httpPutResponseHopLimit")]
+    pub http_put_response_hop_limit: Option<u32>,
+    #[serde(rename = "httpTokens")]
+    pub http_tokens: Option<String>,
+    #[serde(rename = "instanceMetadataTags")]
+    pub instance_metadata_tags: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Monitoring {
+    #[serde(rename = "state")]
+    pub state: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Placement {
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "groupName")]
+    pub group_name: Option<String>,
+    #[serde(rename = "tenancy")]
+    pub tenancy: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceNetworkInterfaceSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<InstanceNetworkInterface>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceNetworkInterface {
+    #[serde(rename = "association")]
+    pub association: Option<InstanceNetworkInterfaceAssociation>,
+    #[serde(rename = "attachment")]
+    pub attachment: Option<InstanceNetworkInterfaceAttachment>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "interfaceType")]
+    pub interface_type: Option<String>,
+    #[serde(rename = "macAddress")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "privateDnsName")]
+    pub private_dns_name: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+    #[serde(rename = "privateIpAddressesSet")]
+    pub private_ip_addresses: Option<InstancePrivateIpAddressSet>,
+    #[serde(rename = "sourceDestCheck")]
+    pub source_dest_check: Option<bool>,
+    #[serde(rename = "status")]
+    pub status: Option<String>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceNetworkInterfaceAssociation {
+    #[serde(rename = "ipOwnerId")]
+    pub ip_owner_id: Option<String>,
+    #[serde(rename = "publicDnsName")]
+    pub public_dns_name: Option<String>,
+    #[serde(rename = "publicIp")]
+    pub public_ip: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceNetworkInterfaceAttachment {
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "attachmentId")]
+    pub attachment_id: Option<String>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "deviceIndex")]
+    pub device_index: Option<u32>,
+    #[serde(rename = "networkCardIndex")]
+    pub network_card_index: Option<u32>,
+    #[serde(rename = "status")]
+    pub status: Option<AttachmentStatus>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstancePrivateIpAddressSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<InstancePrivateIpAddress>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstancePrivateIpAddress {
+    #[serde(rename = "association")]
+    pub association: Option<InstanceNetworkInterfaceAssociation>,
+    #[serde(rename = "primary")]
+    pub primary: Option<bool>,
+    #[serde(rename = "privateDnsName")]
+    pub private_dns_name: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TagSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Tag>>,
+}
+
+impl From<&TagSet> for HashMap<String, String> {
+    fn from(tag_set: &TagSet) -> Self {
+        tag_set
+            .items
+            .iter()
+            .flatten()
+            .filter_map(|tag| {
+                let key = tag.key.clone()?;
+                Some((key, tag.value.clone().unwrap_or_default()))
+            })
+            .collect()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Tag {
+    #[serde(rename = "key")]
+    pub key: Option<String>,
+    #[serde(rename = "value")]
+    pub value: Option<String>,
+}
+
+impl Tag {
+    pub fn new(key: &str, value: &str) -> Self {
+        Self {
+            key: Some(key.into()),
+            value: Some(value.into()),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeLaunchTemplateVersionsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub launch_template_id: Option<String>,
+    pub launch_template_name: Option<String>,
+    pub max_results: Option<u32>,
+    pub max_version: Option<String>,
+    pub min_version: Option<String>,
+    pub next_token: Option<String>,
+    #[serde(rename = "LaunchTemplateVersion")]
+    pub versions: Option<Vec<String>>,
+}
+
+impl DescribeLaunchTemplateVersionsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn launch_template_id(mut self, launch_template_id: &str) -> Self {
+        self.launch_template_id = Some(launch_template_id.into());
+        self
+    }
+
+    pub fn launch_template_name(mut self, launch_template_name: &str) -> Self {
+        self.launch_template_name = Some(launch_template_name.into());
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn max_version(mut self, max_version: &str) -> Self {
+        self.max_version = Some(max_version.into());
+        self
+    }
+
+    pub fn min_version(mut self, min_version: &str) -> Self {
+        self.min_version = Some(min_version.into());
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    // Version numbers, or "$Latest" and "$Default".
+    pub fn versions(mut self, versions: Vec<String>) -> Self {
+        self.versions = Some(versions);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeLaunchTemplateVersionsOutput {
+    #[serde(rename = "launchTemplateVersionSet")]
+    pub launch_template_versions: Option<LaunchTemplateVersionSet>,
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+pub struct DescribeLaunchTemplateVersionsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeLaunchTemplateVersionsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeLaunchTemplateVersionsPaginator<'_> {
+    type Item = Result<DescribeLaunchTemplateVersionsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self
+            .api
+            .describe_launch_template_versions(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateVersionSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<LaunchTemplateVersion>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeLaunchTemplatesInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "LaunchTemplateId")]
+    pub launch_template_ids: Option<Vec<String>>,
+    #[serde(rename = "LaunchTemplateName")]
+    pub launch_template_names: Option<Vec<String>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+}
+
+impl DescribeLaunchTemplatesInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn launch_template_ids(mut self, launch_template_ids: Vec<String>) -> Self {
+        self.launch_template_ids = Some(launch_template_ids);
+        self
+    }
+
+    pub fn launch_template_names(mut self, launch_template_names: Vec<String>) -> Self {
+        self.launch_template_names = Some(launch_template_names);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeLaunchTemplatesOutput {
+    #[serde(rename = "launchTemplates")]
+    pub launch_templates: Option<LaunchTemplateSet>,
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+pub struct DescribeLaunchTemplatesPaginator<'a> {
+    api: &'a Api,
+    input: DescribeLaunchTemplatesInput,
+    done: bool,
+}
+
+impl Iterator for DescribeLaunchTemplatesPaginator<'_> {
+    type Item = Result<DescribeLaunchTemplatesOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_launch_templates(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchTemplateSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<LaunchTemplate>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeNetworkInterfacesInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    #[serde(rename = "NetworkInterfaceId")]
+    pub network_interface_ids: Option<Vec<String>>,
+    pub next_token: Option<String>,
+}
+
+impl DescribeNetworkInterfacesInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn network_interface_ids(mut self, network_interface_ids: Vec<String>) -> Self {
+        self.network_interface_ids = Some(network_interface_ids);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeNetworkInterfacesOutput {
+    #[serde(rename = "networkInterfaceSet")]
+    pub network_interfaces: Option<NetworkInterfaceSet>,
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+pub struct DescribeNetworkInterfacesPaginator<'a> {
+    api: &'a Api,
+    input: DescribeNetworkInterfacesInput,
+    done: bool,
+}
+
+impl Iterator for DescribeNetworkInterfacesPaginator<'_> {
+    type Item = Result<DescribeNetworkInterfacesOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_network_interfaces(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfaceSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<NetworkInterface>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterface {
+    #[serde(rename = "association")]
+    pub association: Option<NetworkInterfaceAssociation>,
+    #[serde(rename = "attachment")]
+    pub attachment: Option<NetworkInterfaceAttachment>,
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "interfaceType")]
+    pub interface_type: Option<String>,
+    #[serde(rename = "macAddress")]
+    pub mac_address: Option<String>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "privateDnsName")]
+    pub private_dns_name: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+    #[serde(rename = "privateIpAddressesSet")]
+    pub private_ip_addresses: Option<NetworkInterfacePrivateIpAddressSet>,
+    #[serde(rename = "requesterId")]
+    pub requester_id: Option<String>,
+    #[serde(rename = "requesterManaged")]
+    pub requester_managed: Option<bool>,
+    #[serde(rename = "sourceDestCheck")]
+    pub source_dest_check: Option<bool>,
+    #[serde(rename = "status")]
+    pub status: Option<NetworkInterfaceStatus>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkInterfaceStatus {
+    Available,
+    Associated,
+    Attaching,
+    InUse,
+    Detaching,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfaceAssociation {
+    #[serde(rename = "allocationId")]
+    pub allocation_id: Option<String>,
+    #[serde(rename = "associationId")]
+    pub association_id: Option<String>,
+    #[serde(rename = "ipOwnerId")]
+    pub ip_owner_id: Option<String>,
+    #[serde(rename = "publicDnsName")]
+    pub public_dns_name: Option<String>,
+    #[serde(rename = "publicIp")]
+    pub public_ip: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfaceAttachment {
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "attachmentId")]
+    pub attachment_id: Option<String>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "deviceIndex")]
+    pub device_index: Option<u32>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceOwnerId")]
+    pub instance_owner_id: Option<String>,
+    #[serde(rename = "networkCardIndex")]
+    pub network_card_index: Option<u32>,
+    #[serde(rename = "status")]
+    pub status: Option<AttachmentStatus>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfacePrivateIpAddressSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<NetworkInterfacePrivateIpAddress>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfacePrivateIpAddress {
+    #[serde(rename = "association")]
+    pub association: Option<NetworkInterfaceAssociation>,
+    #[serde(rename = "primary")]
+    pub primary: Option<bool>,
+    #[serde(rename = "privateDnsName")]
+    pub private_dns_name: Option<String>,
+    #[serde(rename = "privateIpAddress")]
+    pub private_ip_address: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeRouteTablesInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "RouteTableId")]
+    pub route_table_ids: Option<Vec<String>>,
+}
+
+impl DescribeRouteTablesInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn route_table_ids(mut self, route_table_ids: Vec<String>) -> Self {
+        self.route_table_ids = Some(route_table_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeRouteTablesOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "routeTableSet")]
+    pub route_tables: Option<RouteTableSet>,
+}
+
+pub struct DescribeRouteTablesPaginator<'a> {
+    api: &'a Api,
+    input: DescribeRouteTablesInput,
+    done: bool,
+}
+
+impl Iterator for DescribeRouteTablesPaginator<'_> {
+    type Item = Result<DescribeRouteTablesOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_route_tables(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RouteTableSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<RouteTable>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RouteTable {
+    #[serde(rename = "associationSet")]
+    pub associations: Option<RouteTableAssociationSet>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "propagatingVgwSet")]
+    pub propagating_vgws: Option<PropagatingVgwSet>,
+    #[serde(rename = "routeSet")]
+    pub routes: Option<RouteSet>,
+    #[serde(rename = "routeTableId")]
+    pub route_table_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RouteTableAssociationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<RouteTableAssociation>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RouteTableAssociation {
+    #[serde(rename = "associationState")]
+    pub association_state: Option<AssociationState>,
+    #[serde(rename = "gatewayId")]
+    pub gateway_id: Option<String>,
+    #[serde(rename = "main")]
+    pub main: Option<bool>,
+    #[serde(rename = "routeTableAssociationId")]
+    pub route_table_association_id: Option<String>,
+    #[serde(rename = "routeTableId")]
+    pub route_table_id: Option<String>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AssociationState {
+    #[serde(rename = "state")]
+    pub state: Option<String>,
+    #[serde(rename = "statusMessage")]
+    pub status_message: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PropagatingVgwSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<PropagatingVgw>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PropagatingVgw {
+    #[serde(rename = "gatewayId")]
+    pub gateway_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RouteSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Route>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Route {
+    #[serde(rename = "carrierGatewayId")]
+    pub carrier_gateway_id: Option<String>,
+    #[serde(rename = "coreNetworkArn")]
+    pub core_network_arn: Option<String>,
+    #[serde(rename = "destinationCidrBlock")]
+    pub destination_cidr_block: Option<String>,
+    #[serde(rename = "destinationIpv6CidrBlock")]
+    pub destination_ipv6_cidr_block: Option<String>,
+    #[serde(rename = "destinationPrefixListId")]
+    pub destination_prefix_list_id: Option<String>,
+    #[serde(rename = "egressOnlyInternetGatewayId")]
+    pub egress_only_internet_gateway_id: Option<String>,
+    #[serde(rename = "gatewayId")]
+    pub gateway_id: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceOwnerId")]
+    pub instance_owner_id: Option<String>,
+    #[serde(rename = "localGatewayId")]
+    pub local_gateway_id: Option<String>,
+    #[serde(rename = "natGatewayId")]
+    pub nat_gateway_id: Option<String>,
+    #[serde(rename = "networkInterfaceId")]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "origin")]
+    pub origin: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<RouteState>,
+    #[serde(rename = "transitGatewayId")]
+    pub transit_gateway_id: Option<String>,
+    #[serde(rename = "vpcPeeringConnectionId")]
+    pub vpc_peering_connection_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RouteState {
+    Active,
+    Blackhole,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSecurityGroupsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "GroupId")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "GroupName")]
+    pub group_names: Option<Vec<String>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+}
+
+impl DescribeSecurityGroupsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn group_ids(mut self, group_ids: Vec<String>) -> Self {
+        self.group_ids = Some(group_ids);
+        self
+    }
+
+    pub fn group_names(mut self, group_names: Vec<String>) -> Self {
+        self.group_names = Some(group_names);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSecurityGroupsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "securityGroupInfo")]
+    pub security_groups: Option<SecurityGroupSet>,
+}
+
+pub struct DescribeSecurityGroupsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeSecurityGroupsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeSecurityGroupsPaginator<'_> {
+    type Item = Result<DescribeSecurityGroupsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_security_groups(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecurityGroupSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<SecurityGroup>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecurityGroup {
+    #[serde(rename = "groupDescription")]
+    pub description: Option<String>,
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "groupName")]
+    pub group_name: Option<String>,
+    #[serde(rename = "ipPermissions")]
+    pub ip_permissions: Option<IpPermissionSet>,
+    #[serde(rename = "ipPermissionsEgress")]
+    pub ip_permissions_egress: Option<IpPermissionSet>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "securityGroupArn")]
+    pub security_group_arn: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IpPermissionSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<IpPermission>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IpPermission {
+    #[serde(rename = "fromPort")]
+    pub from_port: Option<i32>,
+    #[serde(rename = "groups")]
+    pub groups: Option<UserIdGroupPairSet>,
+    #[serde(rename = "ipProtocol")]
+    pub ip_protocol: String,
+    #[serde(rename = "ipRanges")]
+    pub ip_ranges: Option<IpRangeSet>,
+    #[serde(rename = "ipv6Ranges")]
+    pub ipv6_ranges: Option<Ipv6RangeSet>,
+    #[serde(rename = "prefixListIds")]
+    pub prefix_list_ids: Option<PrefixListIdSet>,
+    #[serde(rename = "toPort")]
+    pub to_port: Option<i32>,
+}
+
+impl IpPermission {
+    // Use a protocol of "-1" for all protocols.
+    pub fn new(ip_protocol: &str) -> Self {
+        Self {
+            ip_protocol: ip_protocol.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn cidr_ip(mut self, cidr_ip: &str) -> Self {
+        let ranges = self.ip_ranges.get_or_insert_with(Default::default);
+        ranges.items.get_or_insert_with(Vec::new).push(IpRange {
+            cidr_ip: Some(cidr_ip.into()),
+            description: None,
+        });
+        self
+    }
+
+    pub fn cidr_ipv6(mut self, cidr_ipv6: &str) -> Self {
+        let ranges = self.ipv6_ranges.get_or_insert_with(Default::default);
+        ranges.items.get_or_insert_with(Vec::new).push(Ipv6Range {
+            cidr_ipv6: Some(cidr_ipv6.into()),
+            description: None,
+        });
+        self
+    }
+
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        let groups = self.groups.get_or_insert_with(Default::default);
+        groups
+            .items
+            .get_or_insert_with(Vec::new)
+            .push(UserIdGroupPair {
+                group_id: Some(group_id.into()),
+                ..Default::default()
+            });
+        self
+    }
+
+    pub fn port(self, port: i32) -> Self {
+        self.port_range(port, port)
+    }
+
+    pub fn port_range(mut self, from_port: i32, to_port: i32) -> Self {
+        self.from_port = Some(from_port);
+        self.to_port = Some(to_port);
+        self
+    }
+
+    pub fn prefix_list_id(mut self, prefix_list_id: &str) -> Self {
+        let prefix_list_ids = self.prefix_list_ids.get_or_insert_with(Default::default);
+        prefix_list_ids
+            .items
+            .get_or_insert_with(Vec::new)
+            .push(PrefixListId {
+                description: None,
+                prefix_list_id: Some(prefix_list_id.into()),
+            });
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IpRangeSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<IpRange>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IpRange {
+    #[serde(rename = "cidrIp")]
+    pub cidr_ip: Option<String>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Ipv6RangeSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Ipv6Range>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Ipv6Range {
+    #[serde(rename = "cidrIpv6")]
+    pub cidr_ipv6: Option<String>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PrefixListIdSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<PrefixListId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PrefixListId {
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "prefixListId")]
+    pub prefix_list_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserIdGroupPairSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<UserIdGroupPair>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserIdGroupPair {
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+    #[serde(rename = "groupName")]
+    pub group_name: Option<String>,
+    #[serde(rename = "peeringStatus")]
+    pub peering_status: Option<String>,
+    #[serde(rename = "userId")]
+    pub user_id: Option<String>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+    #[serde(rename = "vpcPeeringConnectionId")]
+    pub vpc_peering_connection_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSnapshotsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "Owner")]
+    pub owner_ids: Option<Vec<String>>,
+    #[serde(rename = "RestorableBy")]
+    pub restorable_by_user_ids: Option<Vec<String>>,
+    #[serde(rename = "SnapshotId")]
+    pub snapshot_ids: Option<Vec<String>>,
+}
+
+impl DescribeSnapshotsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn owner_ids(mut self, owner_ids: Vec<String>) -> Self {
+        self.owner_ids = Some(owner_ids);
+        self
+    }
+
+    pub fn restorable_by_user_ids(mut self, restorable_by_user_ids: Vec<String>) -> Self {
+        self.restorable_by_user_ids = Some(restorable_by_user_ids);
+        self
+    }
+
+    pub fn snapshot_ids(mut self, snapshot_ids: Vec<String>) -> Self {
+        self.snapshot_ids = Some(snapshot_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSnapshotsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "snapshotSet")]
+    pub snapshots: Option<SnapshotSet>,
+}
+
+pub struct DescribeSnapshotsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeSnapshotsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeSnapshotsPaginator<'_> {
+    type Item = Result<DescribeSnapshotsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_snapshots(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SnapshotSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Snapshot>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Snapshot {
+    #[serde(rename = "dataEncryptionKeyId")]
+    pub data_encryption_key_id: Option<String>,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "encrypted")]
+    pub encrypted: Option<bool>,
+    #[serde(rename = "kmsKeyId")]
+    pub kms_key_id: Option<String>,
+    #[serde(rename = "ownerAlias")]
+    pub owner_alias: Option<String>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "progress")]
+    pub progress: Option<String>,
+    #[serde(rename = "snapshotId")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "startTime")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(rename = "status")]
+    pub state: Option<SnapshotState>,
+    #[serde(rename = "statusMessage")]
+    pub state_message: Option<String>,
+    #[serde(rename = "storageTier")]
+    pub storage_tier: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+    #[serde(rename = "volumeSize")]
+    pub volume_size: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotState {
+    Pending,
+    Completed,
+    Error,
+    Recoverable,
+    Recovering,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSpotInstanceRequestsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "SpotInstanceRequestId")]
+    pub spot_instance_request_ids: Option<Vec<String>>,
+}
+
+impl DescribeSpotInstanceRequestsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn spot_instance_request_ids(mut self, spot_instance_request_ids: Vec<String>) -> Self {
+        self.spot_instance_request_ids = Some(spot_instance_request_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSpotInstanceRequestsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "spotInstanceRequestSet")]
+    pub spot_instance_requests: Option<SpotInstanceRequestSet>,
+}
+
+pub struct DescribeSpotInstanceRequestsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeSpotInstanceRequestsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeSpotInstanceRequestsPaginator<'_> {
+    type Item = Result<DescribeSpotInstanceRequestsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_spot_instance_requests(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotInstanceRequestSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<SpotInstanceRequest>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotInstanceRequest {
+    #[serde(rename = "availabilityZoneGroup")]
+    pub availability_zone_group: Option<String>,
+    #[serde(rename = "createTime")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "fault")]
+    pub fault: Option<SpotInstanceStateFault>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceInterruptionBehavior")]
+    pub instance_interruption_behavior: Option<String>,
+    #[serde(rename = "launchGroup")]
+    pub launch_group: Option<String>,
+    #[serde(rename = "launchSpecification")]
+    pub launch_specification: Option<LaunchSpecification>,
+    #[serde(rename = "launchedAvailabilityZone")]
+    pub launched_availability_zone: Option<String>,
+    #[serde(rename = "productDescription")]
+    pub product_description: Option<String>,
+    #[serde(rename = "type")]
+    pub request_type: Option<String>,
+    #[serde(rename = "spotInstanceRequestId")]
+    pub spot_instance_request_id: Option<String>,
+    #[serde(rename = "spotPrice")]
+    pub spot_price: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<SpotInstanceState>,
+    #[serde(rename = "status")]
+    pub status: Option<SpotInstanceStatus>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "validFrom")]
+    pub valid_from: Option<DateTime<Utc>>,
+    #[serde(rename = "validUntil")]
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpotInstanceState {
+    Open,
+    Active,
+    Closed,
+    Cancelled,
+    Failed,
+    Disabled,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotInstanceStateFault {
+    #[serde(rename = "code")]
+    pub code: Option<String>,
+    #[serde(rename = "message")]
+    pub message: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotInstanceStatus {
+    // For example "pending-evaluation", "fulfilled" or "price-too-low".
+    #[serde(rename = "code")]
+    pub code: Option<String>,
+    #[serde(rename = "message")]
+    pub message: Option<String>,
+    #[serde(rename = "updateTime")]
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LaunchSpecification {
+    #[serde(rename = "blockDeviceMapping")]
+    pub block_device_mappings: Option<BlockDeviceMappingSet>,
+    #[serde(rename = "ebsOptimized")]
+    pub ebs_optimized: Option<bool>,
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "iamInstanceProfile")]
+    pub iam_instance_profile: Option<IamInstanceProfileSpecification>,
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "instanceType")]
+    pub instance_type: Option<String>,
+    #[serde(rename = "keyName")]
+    pub key_name: Option<String>,
+    #[serde(rename = "placement")]
+    pub placement: Option<Placement>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSpotPriceHistoryInput {
+    pub availability_zone: Option<String>,
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    #[serde(rename = "InstanceType")]
+    pub instance_types: Option<Vec<String>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "ProductDescription")]
+    pub product_descriptions: Option<Vec<String>>,
+    pub start_time: Option<DateTime<Utc>>,
+}
+
+impl DescribeSpotPriceHistoryInput {
+    pub fn availability_zone(mut self, availability_zone: &str) -> Self {
+        self.availability_zone = Some(availability_zone.into());
+        self
+    }
+
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn instance_types(mut self, instance_types: Vec<String>) -> Self {
+        self.instance_types = Some(instance_types);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    // For example "Linux/UNIX" or "Windows".
+    pub fn product_descriptions(mut self, product_descriptions: Vec<String>) -> Self {
+        self.product_descriptions = Some(product_descriptions);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSpotPriceHistoryOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "spotPriceHistorySet")]
+    pub spot_price_history: Option<SpotPriceSet>,
+}
+
+pub struct DescribeSpotPriceHistoryPaginator<'a> {
+    api: &'a Api,
+    input: DescribeSpotPriceHistoryInput,
+    done: bool,
+}
+
+impl Iterator for DescribeSpotPriceHistoryPaginator<'_> {
+    type Item = Result<DescribeSpotPriceHistoryOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_spot_price_history(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotPriceSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<SpotPrice>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpotPrice {
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "instanceType")]
+    pub instance_type: Option<String>,
+    #[serde(rename = "productDescription")]
+    pub product_description: Option<String>,
+    #[serde(rename = "spotPrice")]
+    pub spot_price: Option<String>,
+    #[serde(rename = "timestamp")]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSubnetsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "SubnetId")]
+    pub subnet_ids: Option<Vec<String>>,
+}
+
+impl DescribeSubnetsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn subnet_ids(mut self, subnet_ids: Vec<String>) -> Self {
+        self.subnet_ids = Some(subnet_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeSubnetsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "subnetSet")]
+    pub subnets: Option<SubnetSet>,
+}
+
+pub struct DescribeSubnetsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeSubnetsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeSubnetsPaginator<'_> {
+    type Item = Result<DescribeSubnetsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_subnets(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubnetSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Subnet>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Subnet {
+    #[serde(rename = "assignIpv6AddressOnCreation")]
+    pub assign_ipv6_address_on_creation: Option<bool>,
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "availabilityZoneId")]
+    pub availability_zone_id: Option<String>,
+    #[serde(rename = "availableIpAddressCount")]
+    pub available_ip_address_count: Option<u32>,
+    #[serde(rename = "cidrBlock")]
+    pub cidr_block: Option<String>,
+    #[serde(rename = "defaultForAz")]
+    pub default_for_az: Option<bool>,
+    #[serde(rename = "ipv6CidrBlockAssociationSet")]
+    pub ipv6_cidr_block_associations: Option<Ipv6CidrBlockAssociationSet>,
+    #[serde(rename = "mapPublicIpOnLaunch")]
+    pub map_public_ip_on_launch: Option<bool>,
+    #[serde(rename = "outpostArn")]
+    pub outpost_arn: Option<String>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<SubnetState>,
+    #[serde(rename = "subnetArn")]
+    pub subnet_arn: Option<String>,
+    #[serde(rename = "subnetId")]
+    pub subnet_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubnetState {
+    Pending,
+    Available,
+    Unavailable,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Ipv6CidrBlockAssociationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Ipv6CidrBlockAssociation>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Ipv6CidrBlockAssociation {
+    #[serde(rename = "associationId")]
+    pub association_id: Option<String>,
+    #[serde(rename = "ipv6CidrBlock")]
+    pub ipv6_cidr_block: Option<String>,
+    #[serde(rename = "ipv6CidrBlockState")]
+    pub ipv6_cidr_block_state: Option<CidrBlockState>,
+    #[serde(rename = "ipv6Pool")]
+    pub ipv6_pool: Option<String>,
+    #[serde(rename = "networkBorderGroup")]
+    pub network_border_group: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CidrBlockState {
+    #[serde(rename = "state")]
+    pub state: Option<String>,
+    #[serde(rename = "statusMessage")]
+    pub status_message: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeTagsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+}
+
+impl DescribeTagsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeTagsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagDescriptionSet>,
+}
+
+pub struct DescribeTagsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeTagsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeTagsPaginator<'_> {
+    type Item = Result<DescribeTagsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_tags(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TagDescriptionSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<TagDescription>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TagDescription {
+    #[serde(rename = "key")]
+    pub key: Option<String>,
+    #[serde(rename = "resourceId")]
+    pub resource_id: Option<String>,
+    #[serde(rename = "resourceType")]
+    pub resource_type: Option<String>,
+    #[serde(rename = "value")]
+    pub value: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVolumesInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "VolumeId")]
+    pub volume_ids: Option<Vec<String>>,
+}
+
+impl DescribeVolumesInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn volume_ids(mut self, volume_ids: Vec<String>) -> Self {
+        self.volume_ids = Some(volume_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVolumesOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "volumeSet")]
+    pub volumes: Option<VolumeSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VolumeSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Volume>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Volume {
+    #[serde(rename = "attachmentSet")]
+    pub attachments: Option<AttachmentSet>,
+    #[serde(rename = "availabilityZone")]
+    pub availability_zone: Option<String>,
+    #[serde(rename = "availabilityZoneId")]
+    pub availability_zone_id: Option<String>,
+    #[serde(rename = "createTime")]
+    pub create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "encrypted")]
+    pub encrypted: Option<bool>,
+    #[serde(rename = "iops")]
+    pub iops: Option<u32>,
+    #[serde(rename = "kmsKeyId")]
+    pub kms_key_id: Option<String>,
+    #[serde(rename = "multiAttachEnabled")]
+    pub multi_attach_enabled: Option<bool>,
+    #[serde(rename = "size")]
+    pub size: Option<u32>,
+    #[serde(rename = "snapshotId")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "status")]
+    pub state: Option<VolumeState>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "throughput")]
+    pub throughput: Option<u32>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+    #[serde(rename = "volumeType")]
+    pub volume_type: Option<VolumeType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VolumeState {
+    Creating,
+    Available,
+    InUse,
+    Deleting,
+    Deleted,
+    Error,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VolumeType {
+    Standard,
+    Io1,
+    Io2,
+    Gp2,
+    Gp3,
+    Sc1,
+    St1,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachmentSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Attachment>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Attachment {
+    #[serde(rename = "associatedResource")]
+    pub associated_resource: Option<String>,
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "device")]
+    pub device: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceOwningService")]
+    pub instance_owning_service: Option<String>,
+    #[serde(rename = "status")]
+    pub status: Option<AttachmentStatus>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttachmentStatus {
+    Attaching,
+    Attached,
+    Detaching,
+    Detached,
+    Busy,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[deprecated(note = "renamed to AttachmentStatus")]
+pub type Status = AttachmentStatus;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVolumesModificationsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "VolumeId")]
+    pub volume_ids: Option<Vec<String>>,
+}
+
+impl DescribeVolumesModificationsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn volume_ids(mut self, volume_ids: Vec<String>) -> Self {
+        self.volume_ids = Some(volume_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVolumesModificationsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "volumeModificationSet")]
+    pub volume_modifications: Option<VolumeModificationSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VolumeModificationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<VolumeModification>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VolumeModification {
+    #[serde(rename = "endTime")]
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(rename = "modificationState")]
+    pub modification_state: Option<VolumeModificationState>,
+    #[serde(rename = "originalIops")]
+    pub original_iops: Option<u32>,
+    #[serde(rename = "originalMultiAttachEnabled")]
+    pub original_multi_attach_enabled: Option<bool>,
+    #[serde(rename = "originalSize")]
+    pub original_size: Option<u32>,
+    #[serde(rename = "originalThroughput")]
+    pub original_throughput: Option<u32>,
+    #[serde(rename = "originalVolumeType")]
+    pub original_volume_type: Option<VolumeType>,
+    #[serde(rename = "progress")]
+    pub progress: Option<u32>,
+    #[serde(rename = "startTime")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(rename = "statusMessage")]
+    pub status_message: Option<String>,
+    #[serde(rename = "targetIops")]
+    pub target_iops: Option<u32>,
+    #[serde(rename = "targetMultiAttachEnabled")]
+    pub target_multi_attach_enabled: Option<bool>,
+    #[serde(rename = "targetSize")]
+    pub target_size: Option<u32>,
+    #[serde(rename = "targetThroughput")]
+    pub target_throughput: Option<u32>,
+    #[serde(rename = "targetVolumeType")]
+    pub target_volume_type: Option<VolumeType>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VolumeModificationState {
+    Modifying,
+    Optimizing,
+    Completed,
+    Failed,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVpcsInput {
+    #[serde(rename = "Filter")]
+    pub filters: Option<Vec<Filter>>,
+    pub max_results: Option<u32>,
+    pub next_token: Option<String>,
+    #[serde(rename = "VpcId")]
+    pub vpc_ids: Option<Vec<String>>,
+}
+
+impl DescribeVpcsInput {
+    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
+        self.filters = Some(filters);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn next_token(mut self, next_token: &str) -> Self {
+        self.next_token = Some(next_token.into());
+        self
+    }
+
+    pub fn vpc_ids(mut self, vpc_ids: Vec<String>) -> Self {
+        self.vpc_ids = Some(vpc_ids);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DescribeVpcsOutput {
+    #[serde(rename = "nextToken")]
+    pub next_token: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "vpcSet")]
+    pub vpcs: Option<VpcSet>,
+}
+
+pub struct DescribeVpcsPaginator<'a> {
+    api: &'a Api,
+    input: DescribeVpcsInput,
+    done: bool,
+}
+
+impl Iterator for DescribeVpcsPaginator<'_> {
+    type Item = Result<DescribeVpcsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.describe_vpcs(self.input.clone());
+        match &result {
+            Ok(output) => match &output.next_token {
+                Some(token) if !token.is_empty() => self.input.next_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VpcSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<Vpc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Vpc {
+    #[serde(rename = "cidrBlock")]
+    pub cidr_block: Option<String>,
+    #[serde(rename = "cidrBlockAssociationSet")]
+    pub cidr_block_associations: Option<CidrBlockAssociationSet>,
+    #[serde(rename = "dhcpOptionsId")]
+    pub dhcp_options_id: Option<String>,
+    #[serde(rename = "instanceTenancy")]
+    pub instance_tenancy: Option<String>,
+    #[serde(rename = "ipv6CidrBlockAssociationSet")]
+    pub ipv6_cidr_block_associations: Option<Ipv6CidrBlockAssociationSet>,
+    #[serde(rename = "isDefault")]
+    pub is_default: Option<bool>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "state")]
+    pub state: Option<VpcState>,
+    #[serde(rename = "tagSet")]
+    pub tags: Option<TagSet>,
+    #[serde(rename = "vpcId")]
+    pub vpc_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VpcState {
+    Pending,
+    Available,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CidrBlockAssociationSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<CidrBlockAssociation>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CidrBlockAssociation {
+    #[serde(rename = "associationId")]
+    pub association_id: Option<String>,
+    #[serde(rename = "cidrBlock")]
+    pub cidr_block: Option<String>,
+    #[serde(rename = "cidrBlockState")]
+    pub cidr_block_state: Option<CidrBlockState>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DetachNetworkInterfaceInput {
+    pub attachment_id: String,
+    pub force: Option<bool>,
+}
+
+impl DetachNetworkInterfaceInput {
+    pub fn attachment_id(mut self, attachment_id: &str) -> Self {
+        self.attachment_id = attachment_id.into();
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DetachNetworkInterfaceOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DetachVolumeInput {
+    pub device: Option<String>,
+    pub force: Option<bool>,
+    pub instance_id: Option<String>,
+    pub volume_id: String,
+}
+
+impl DetachVolumeInput {
+    pub fn device(mut self, device: &str) -> Self {
+        self.device = Some(device.into());
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
+    pub fn volume_id(mut self, volume_id: &str) -> Self {
+        self.volume_id = volume_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DetachVolumeOutput {
+    #[serde(rename = "associatedResource")]
+    pub associated_resource: Option<String>,
+    #[serde(rename = "attachTime")]
+    pub attach_time: Option<DateTime<Utc>>,
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
+    #[serde(rename = "device")]
+    pub device: Option<String>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceOwningService")]
+    pub instance_owning_service: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "status")]
+    pub status: Option<AttachmentStatus>,
+    #[serde(rename = "volumeId")]
+    pub volume_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisassociateAddressInput {
+    pub association_id: String,
+}
+
+impl DisassociateAddressInput {
+    pub fn association_id(mut self, association_id: &str) -> Self {
+        self.association_id = association_id.into();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisassociateAddressOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetConsoleOutputInput {
+    pub instance_id: String,
+    pub latest: Option<bool>,
+}
+
+impl GetConsoleOutputInput {
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    // Only supported on instances built on the Nitro System.
+    pub fn latest(mut self, latest: bool) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetConsoleOutputOutput {
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "output", default, deserialize_with = "from_base64_lossy")]
+    pub output: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "timestamp")]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetConsoleScreenshotInput {
+    pub instance_id: String,
+    pub wake_up: Option<bool>,
+}
+
+impl GetConsoleScreenshotInput {
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    pub fn wake_up(mut self, wake_up: bool) -> Self {
+        self.wake_up = Some(wake_up);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetConsoleScreenshotOutput {
+    // JPG image data.
+    #[serde(rename = "imageData", default, deserialize_with = "from_base64")]
+    pub image_data: Option<Vec<u8>>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyInstanceAttributeInput {
+    #[serde(rename = "DisableApiStop.Value")]
+    pub disable_api_stop: Option<bool>,
+    #[serde(rename = "DisableApiTermination.Value")]
+    pub disable_api_termination: Option<bool>,
+    #[serde(rename = "EbsOptimized.Value")]
+    pub ebs_optimized: Option<bool>,
+    #[serde(rename = "GroupId")]
+    pub groups: Option<Vec<String>>,
+    pub instance_id: String,
+    #[serde(rename = "InstanceInitiatedShutdownBehavior.Value")]
+    pub instance_initiated_shutdown_behavior: Option<String>,
+    #[serde(rename = "InstanceType.Value")]
+    pub instance_type: Option<String>,
+    #[serde(rename = "SourceDestCheck.Value")]
+    pub source_dest_check: Option<bool>,
+    #[serde(rename = "UserData.Value", serialize_with = "to_base64")]
+    pub user_data: Option<String>,
+}
+
+impl ModifyInstanceAttributeInput {
+    pub fn disable_api_stop(mut self, disable_api_stop: bool) -> Self {
+        self.disable_api_stop = Some(disable_api_stop);
+        self
+    }
+
+    pub fn disable_api_termination(mut self, disable_api_termination: bool) -> Self {
+        self.disable_api_termination = Some(disable_api_termination);
+        self
+    }
+
+    pub fn ebs_optimized(mut self, ebs_optimized: bool) -> Self {
+        self.ebs_optimized = Some(ebs_optimized);
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    pub fn instance_initiated_shutdown_behavior(mut self, behavior: &str) -> Self {
+        self.instance_initiated_shutdown_behavior = Some(behavior.into());
+        self
+    }
+
+    pub fn instance_type(mut self, instance_type: &str) -> Self {
+        self.instance_type = Some(instance_type.into());
+        self
+    }
+
+    pub fn source_dest_check(mut self, source_dest_check: bool) -> Self {
+        self.source_dest_check = Some(source_dest_check);
+        self
+    }
+
+    // Plain text, base64 encoded when sent.
+    pub fn user_data(mut self, user_data: &str) -> Self {
+        self.user_data = Some(user_data.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyInstanceAttributeOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyInstanceMetadataOptionsInput {
+    pub http_endpoint: Option<String>,
+    pub http_protocol_ipv6: Option<String>,
+    pub http_put_response_hop_limit: Option<u32>,
+    pub http_tokens: Option<String>,
+    pub instance_id: String,
+    pub instance_metadata_tags: Option<String>,
+}
+
+impl ModifyInstanceMetadataOptionsInput {
+    pub fn http_endpoint(mut self, http_endpoint: &str) -> Self {
+        self.http_endpoint = Some(http_endpoint.into());
+        self
+    }
+
+    pub fn http_protocol_ipv6(mut self, http_protocol_ipv6: &str) -> Self {
+        self.http_protocol_ipv6 = Some(http_protocol_ipv6.into());
+        self
+    }
+
+    pub fn http_put_response_hop_limit(mut self, hop_limit: u32) -> Self {
+        self.http_put_response_hop_limit = Some(hop_limit);
+        self
+    }
+
+    // "required" enforces IMDSv2.
+    pub fn http_tokens(mut self, http_tokens: &str) -> Self {
+        self.http_tokens = Some(http_tokens.into());
+        self
+    }
+
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    pub fn instance_metadata_tags(mut self, instance_metadata_tags: &str) -> Self {
+        self.instance_metadata_tags = Some(instance_metadata_tags.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyInstanceMetadataOptionsOutput {
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "instanceMetadataOptions")]
+    pub instance_metadata_options: Option<InstanceMetadataOptions>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyNetworkInterfaceAttributeInput {
+    pub attachment: Option<NetworkInterfaceAttachmentChanges>,
+    #[serde(rename = "Description.Value")]
+    pub description: Option<String>,
+    #[serde(rename = "SecurityGroupId")]
+    pub groups: Option<Vec<String>>,
+    pub network_interface_id: String,
+    #[serde(rename = "SourceDestCheck.Value")]
+    pub source_dest_check: Option<bool>,
+}
+
+impl ModifyNetworkInterfaceAttributeInput {
+    pub fn attachment(mut self, attachment: NetworkInterfaceAttachmentChanges) -> Self {
+        self.attachment = Some(attachment);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<String>) -> Self {
+        self.groups = Some(groups);
+        self
+    }
+
+    pub fn network_interface_id(mut self, network_interface_id: &str) -> Self {
+        self.network_interface_id = network_interface_id.into();
+        self
+    }
+
+    pub fn source_dest_check(mut self, source_dest_check: bool) -> Self {
+        self.source_dest_check = Some(source_dest_check);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NetworkInterfaceAttachmentChanges {
+    pub attachment_id: String,
+    pub delete_on_termination: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyNetworkInterfaceAttributeOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyVolumeInput {
+    pub iops: Option<u32>,
+    pub multi_attach_enabled: Option<bool>,
+    pub size: Option<u32>,
+    pub throughput: Option<u32>,
+    pub volume_id: String,
+    pub volume_type: Option<VolumeType>,
+}
+
+impl ModifyVolumeInput {
+    pub fn iops(mut self, iops: u32) -> Self {
+        self.iops = Some(iops);
+        self
+    }
+
+    pub fn multi_attach_enabled(mut self, multi_attach_enabled: bool) -> Self {
+        self.multi_attach_enabled = Some(multi_attach_enabled);
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn throughput(mut self, throughput: u32) -> Self {
+        self.throughput = Some(throughput);
+        self
+    }
+
+    pub fn volume_id(mut self, volume_id: &str) -> Self {
+        self.volume_id = volume_id.into();
+        self
+    }
+
+    pub fn volume_type(mut self, volume_type: VolumeType) -> Self {
+        self.volume_type = Some(volume_type);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ModifyVolumeOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "volumeModification")]
+    pub volume_modification: Option<VolumeModification>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RebootInstancesInput {
+    #[serde(rename = "InstanceId")]
+    pub instance_ids: Vec<String>,
+}
+
+impl RebootInstancesInput {
+    pub fn instance_ids(mut self, instance_ids: Vec<String>) -> Self {
+        self.instance_ids = instance_ids;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RebootInstancesOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RegisterImageInput {
+    pub architecture: Option<String>,
+    #[serde(rename = "BlockDeviceMapping")]
+    pub block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    pub boot_mode: Option<String>,
+    pub description: Option<String>,
+    pub ena_support: Option<bool>,
+    pub image_location: Option<String>,
+    pub imds_support: Option<String>,
+    pub kernel_id: Option<String>,
+    pub name: String,
+    pub ramdisk_id: Option<String>,
+    pub root_device_name: Option<String>,
+    pub sriov_net_support: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub tpm_support: Option<String>,
+    pub uefi_data: Option<String>,
+    pub virtualization_type: Option<String>,
+}
+
+impl RegisterImageInput {
+    pub fn architecture(mut self, architecture: &str) -> Self {
+        self.architecture = Some(architecture.into());
+        self
+    }
+
+    pub fn block_device_mappings(mut self, block_device_mappings: Vec<BlockDeviceMapping>) -> Self {
+        self.block_device_mappings = Some(block_device_mappings);
+        self
+    }
+
+    // One of "legacy-bios", "uefi" or "uefi-preferred".
+    pub fn boot_mode(mut self, boot_mode: &str) -> Self {
+        self.boot_mode = Some(boot_mode.into());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn ena_support(mut self, ena_support: bool) -> Self {
+        self.ena_support = Some(ena_support);
+        self
+    }
+
+    pub fn image_location(mut self, image_location: &str) -> Self {
+        self.image_location = Some(image_location.into());
+        self
+    }
+
+    pub fn imds_support(mut self, imds_support: &str) -> Self {
+        self.imds_support = Some(imds_support.into());
+        self
+    }
+
+    pub fn kernel_id(mut self, kernel_id: &str) -> Self {
+        self.kernel_id = Some(kernel_id.into());
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn ramdisk_id(mut self, ramdisk_id: &str) -> Self {
+        self.ramdisk_id = Some(ramdisk_id.into());
+        self
+    }
+
+    pub fn root_device_name(mut self, root_device_name: &str) -> Self {
+        self.root_device_name = Some(root_device_name.into());
+        self
+    }
+
+    // Only "simple" is supported, enabling the Intel 82599 VF interface.
+    pub fn sriov_net_support(mut self, sriov_net_support: &str) -> Self {
+        self.sriov_net_support = Some(sriov_net_support.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn tpm_support(mut self, tpm_support: &str) -> Self {
+        self.tpm_support = Some(tpm_support.into());
+        self
+    }
+
+    pub fn uefi_data(mut self, uefi_data: &str) -> Self {
+        self.uefi_data = Some(uefi_data.into());
+        self
+    }
+
+    pub fn virtualization_type(mut self, virtualization_type: &str) -> Self {
+        self.virtualization_type = Some(virtualization_type.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RegisterImageOutput {
+    #[serde(rename = "imageId")]
+    pub image_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReleaseAddressInput {
+    pub allocation_id: String,
+    pub network_border_group: Option<String>,
+}
+
+impl ReleaseAddressInput {
+    pub fn allocation_id(mut self, allocation_id: &str) -> Self {
+        self.allocation_id = allocation_id.into();
+        self
+    }
+
+    pub fn network_border_group(mut self, network_border_group: &str) -> Self {
+        self.network_border_group = Some(network_border_group.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReleaseAddressOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RequestSpotInstancesInput {
+    pub availability_zone_group: Option<String>,
+    pub client_token: Option<String>,
+    pub instance_count: Option<u32>,
+    pub instance_interruption_behavior: Option<String>,
+    pub launch_group: Option<String>,
+    pub launch_specification: Option<RequestSpotLaunchSpecification>,
+    #[serde(rename = "Type")]
+    pub request_type: Option<String>,
+    pub spot_price: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    pub valid_from: Option<DateTime<Utc>>,
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+impl RequestSpotInstancesInput {
+    pub fn availability_zone_group(mut self, availability_zone_group: &str) -> Self {
+        self.availability_zone_group = Some(availability_zone_group.into());
+        self
+    }
+
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn instance_count(mut self, instance_count: u32) -> Self {
+        self.instance_count = Some(instance_count);
+        self
+    }
+
+    // One of "hibernate", "stop" or "terminate".
+    pub fn instance_interruption_behavior(mut self, behavior: &str) -> Self {
+        self.instance_interruption_behavior = Some(behavior.into());
+        self
+    }
+
+    pub fn launch_group(mut self, launch_group: &str) -> Self {
+        self.launch_group = Some(launch_group.into());
+        self
+    }
+
+    pub fn launch_specification(
+        mut self,
+        launch_specification: RequestSpotLaunchSpecification,
+    ) -> Self {
+        self.launch_specification = Some(launch_specification);
+        self
+    }
+
+    // One of "one-time" or "persistent".
+    pub fn request_type(mut self, request_type: &str) -> Self {
+        self.request_type = Some(request_type.into());
+        self
+    }
+
+    // Defaults to the on-demand price when not set.
+    pub fn spot_price(mut self, spot_price: &str) -> Self {
+        self.spot_price = Some(spot_price.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    pub fn valid_from(mut self, valid_from: DateTime<Utc>) -> Self {
+        self.valid_from = Some(valid_from);
+        self
+    }
+
+    pub fn valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RequestSpotInstancesOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "spotInstanceRequestSet")]
+    pub spot_instance_requests: Option<SpotInstanceRequestSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RequestSpotLaunchSpecification {
+    #[serde(rename = "BlockDeviceMapping")]
+    pub block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    pub ebs_optimized: Option<bool>,
+    pub iam_instance_profile: Option<IamInstanceProfileSpecification>,
+    pub image_id: Option<String>,
+    pub instance_type: Option<String>,
+    pub key_name: Option<String>,
+    #[serde(rename = "Monitoring.Enabled")]
+    pub monitoring: Option<bool>,
+    #[serde(rename = "NetworkInterface")]
+    pub network_interfaces: Option<Vec<InstanceNetworkInterfaceSpecification>>,
+    pub placement: Option<Placement>,
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_ids: Option<Vec<String>>,
+    pub subnet_id: Option<String>,
+    #[serde(serialize_with = "to_base64")]
+    pub user_data: Option<String>,
+}
+
+impl RequestSpotLaunchSpecification {
+    pub fn block_device_mappings(mut self, block_device_mappings: Vec<BlockDeviceMapping>) -> Self {
+        self.block_device_mappings = Some(block_device_mappings);
+        self
+    }
+
+    pub fn ebs_optimized(mut self, ebs_optimized: bool) -> Self {
+        self.ebs_optimized = Some(ebs_optimized);
+        self
+    }
+
+    pub fn iam_instance_profile(
+        mut self,
+        iam_instance_profile: IamInstanceProfileSpecification,
+    ) -> Self {
+        self.iam_instance_profile = Some(iam_instance_profile);
+        self
+    }
+
+    pub fn image_id(mut self, image_id: &str) -> Self {
+        self.image_id = Some(image_id.into());
+        self
+    }
+
+    pub fn instance_type(mut self, instance_type: &str) -> Self {
+        self.instance_type = Some(instance_type.into());
+        self
+    }
+
+    pub fn key_name(mut self, key_name: &str) -> Self {
+        self.key_name = Some(key_name.into());
+        self
+    }
+
+    pub fn monitoring(mut self, monitoring: bool) -> Self {
+        self.monitoring = Some(monitoring);
+        self
+    }
+
+    pub fn network_interfaces(
+        mut self,
+        network_interfaces: Vec<InstanceNetworkInterfaceSpecification>,
+    ) -> Self {
+        self.network_interfaces = Some(network_interfaces);
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    pub fn security_group_ids(mut self, security_group_ids: Vec<String>) -> Self {
+        self.security_group_ids = Some(security_group_ids);
+        self
+    }
+
+    pub fn subnet_id(mut self, subnet_id: &str) -> Self {
+        self.subnet_id = Some(subnet_id.into());
+        self
+    }
+
+    // Plain text, base64 encoded when sent.
+    pub fn user_data(mut self, user_data: &str) -> Self {
+        self.user_data = Some(user_data.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RevokeSecurityGroupEgressInput {
+    pub group_id: String,
+    pub ip_permissions: Vec<IpPermission>,
 }
 
-impl DescribeVolumesInput {
-    pub fn filters(mut self, filters: Vec<Filter>) -> Self {
-        self.filters = Some(filters);
+impl RevokeSecurityGroupEgressInput {
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = group_id.into();
         self
     }
 
-    pub fn max_results(mut self, max_results: u32) -> Self {
-        self.max_results = Some(max_results);
+    pub fn ip_permissions(mut self, ip_permissions: Vec<IpPermission>) -> Self {
+        self.ip_permissions = ip_permissions;
         self
     }
+}
 
-    pub fn next_token(mut self, next_token: &str) -> Self {
-        self.next_token = Some(next_token.into());
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RevokeSecurityGroupEgressOutput {
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+    #[serde(rename = "unknownIpPermissionSet")]
+    pub unknown_ip_permissions: Option<IpPermissionSet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RevokeSecurityGroupIngressInput {
+    pub group_id: String,
+    pub ip_permissions: Vec<IpPermission>,
+}
+
+impl RevokeSecurityGroupIngressInput {
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = group_id.into();
         self
     }
 
-    pub fn volume_ids(mut self, volume_ids: Vec<String>) -> Self {
-        self.volume_ids = Some(volume_ids);
+    pub fn ip_permissions(mut self, ip_permissions: Vec<IpPermission>) -> Self {
+        self.ip_permissions = ip_permissions;
         self
     }
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct DescribeVolumesOutput {
-    #[serde(rename = "nextToken")]
-    pub next_token: Option<String>,
+pub struct RevokeSecurityGroupIngressOutput {
     #[serde(rename = "requestId")]
     pub request_id: Option<String>,
-    #[serde(rename = "volumeSet")]
-    pub volumes: Option<VolumeSet>,
+    #[serde(rename = "return")]
+    pub r#return: Option<bool>,
+    #[serde(rename = "unknownIpPermissionSet")]
+    pub unknown_ip_permissions: Option<IpPermissionSet>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct VolumeSet {
-    #[serde(rename = "item")]
-    pub items: Option<Vec<Volume>>,
+pub struct RunInstancesInput {
+    #[serde(rename = "BlockDeviceMapping")]
+    pub block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    pub client_token: Option<String>,
+    pub disable_api_termination: Option<bool>,
+    pub ebs_optimized: Option<bool>,
+    pub iam_instance_profile: Option<IamInstanceProfileSpecification>,
+    pub image_id: Option<String>,
+    pub instance_initiated_shutdown_behavior: Option<String>,
+    pub instance_type: Option<String>,
+    pub key_name: Option<String>,
+    pub launch_template: Option<LaunchTemplateSpecification>,
+    pub max_count: u32,
+    pub metadata_options: Option<InstanceMetadataOptionsRequest>,
+    pub min_count: u32,
+    #[serde(rename = "NetworkInterface")]
+    pub network_interfaces: Option<Vec<InstanceNetworkInterfaceSpecification>>,
+    pub placement: Option<Placement>,
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_ids: Option<Vec<String>>,
+    pub subnet_id: Option<String>,
+    #[serde(rename = "TagSpecification")]
+    pub tag_specifications: Option<Vec<TagSpecification>>,
+    #[serde(serialize_with = "to_base64")]
+    pub user_data: Option<String>,
+}
+
+impl RunInstancesInput {
+    pub fn block_device_mappings(mut self, block_device_mappings: Vec<BlockDeviceMapping>) -> Self {
+        self.block_device_mappings = Some(block_device_mappings);
+        self
+    }
+
+    pub fn client_token(mut self, client_token: &str) -> Self {
+        self.client_token = Some(client_token.into());
+        self
+    }
+
+    pub fn disable_api_termination(mut self, disable_api_termination: bool) -> Self {
+        self.disable_api_termination = Some(disable_api_termination);
+        self
+    }
+
+    pub fn ebs_optimized(mut self, ebs_optimized: bool) -> Self {
+        self.ebs_optimized = Some(ebs_optimized);
+        self
+    }
+
+    pub fn iam_instance_profile(
+        mut self,
+        iam_instance_profile: IamInstanceProfileSpecification,
+    ) -> Self {
+        self.iam_instance_profile = Some(iam_instance_profile);
+        self
+    }
+
+    pub fn image_id(mut self, image_id: &str) -> Self {
+        self.image_id = Some(image_id.into());
+        self
+    }
+
+    pub fn instance_initiated_shutdown_behavior(mut self, behavior: &str) -> Self {
+        self.instance_initiated_shutdown_behavior = Some(behavior.into());
+        self
+    }
+
+    pub fn instance_type(mut self, instance_type: &str) -> Self {
+        self.instance_type = Some(instance_type.into());
+        self
+    }
+
+    pub fn key_name(mut self, key_name: &str) -> Self {
+        self.key_name = Some(key_name.into());
+        self
+    }
+
+    pub fn launch_template(mut self, launch_template: LaunchTemplateSpecification) -> Self {
+        self.launch_template = Some(launch_template);
+        self
+    }
+
+    pub fn max_count(mut self, max_count: u32) -> Self {
+        self.max_count = max_count;
+        self
+    }
+
+    pub fn metadata_options(mut self, metadata_options: InstanceMetadataOptionsRequest) -> Self {
+        self.metadata_options = Some(metadata_options);
+        self
+    }
+
+    pub fn min_count(mut self, min_count: u32) -> Self {
+        self.min_count = min_count;
+        self
+    }
+
+    pub fn network_interfaces(
+        mut self,
+        network_interfaces: Vec<InstanceNetworkInterfaceSpecification>,
+    ) -> Self {
+        self.network_interfaces = Some(network_interfaces);
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    pub fn security_group_ids(mut self, security_group_ids: Vec<String>) -> Self {
+        self.security_group_ids = Some(security_group_ids);
+        self
+    }
+
+    pub fn subnet_id(mut self, subnet_id: &str) -> Self {
+        self.subnet_id = Some(subnet_id.into());
+        self
+    }
+
+    pub fn tag_specifications(mut self, tag_specifications: Vec<TagSpecification>) -> Self {
+        self.tag_specifications = Some(tag_specifications);
+        self
+    }
+
+    // The raw user data; it is base64 encoded when the request is sent.
+    pub fn user_data(mut self, user_data: &str) -> Self {
+        self.user_data = Some(user_data.into());
+        self
+    }
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Volume {
-    #[serde(rename = "attachments")]
-    pub attachments: Option<AttachmentSet>,
-    #[serde(rename = "availabilityZone")]
-    pub availability_zone: Option<String>,
-    #[serde(rename = "availabilityZoneId")]
-    pub availability_zone_id: Option<String>,
-    #[serde(rename = "createTime")]
-    pub create_time: Option<String>,
+pub struct BlockDeviceMapping {
+    #[serde(rename = "deviceName")]
+    pub device_name: Option<String>,
+    #[serde(rename = "ebs")]
+    pub ebs: Option<EbsBlockDevice>,
+    #[serde(rename = "noDevice")]
+    pub no_device: Option<String>,
+    #[serde(rename = "virtualName")]
+    pub virtual_name: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EbsBlockDevice {
+    #[serde(rename = "deleteOnTermination")]
+    pub delete_on_termination: Option<bool>,
     #[serde(rename = "encrypted")]
     pub encrypted: Option<bool>,
     #[serde(rename = "iops")]
     pub iops: Option<u32>,
     #[serde(rename = "kmsKeyId")]
     pub kms_key_id: Option<String>,
-    #[serde(rename = "size")]
-    pub size: Option<u32>,
     #[serde(rename = "snapshotId")]
     pub snapshot_id: Option<String>,
-    #[serde(rename = "state")]
-    pub state: Option<String>,
-    #[serde(rename = "volumeId")]
-    pub volume_id: Option<String>,
+    #[serde(rename = "throughput")]
+    pub throughput: Option<u32>,
+    #[serde(rename = "volumeSize")]
+    pub volume_size: Option<u32>,
     #[serde(rename = "volumeType")]
-    pub volume_type: Option<String>,
+    pub volume_type: Option<VolumeType>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AttachmentSet {
-    #[serde(rename = "item")]
-    pub items: Option<Vec<Attachment>>,
+pub struct IamInstanceProfileSpecification {
+    pub arn: Option<String>,
+    pub name: Option<String>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Attachment {
-    #[serde(rename = "associatedResource")]
-    pub associated_resource: Option<String>,
-    #[serde(rename = "attachTime")]
-    pub attach_time: Option<DateTime<Utc>>,
-    #[serde(rename = "deleteOnTermination")]
+pub struct LaunchTemplateSpecification {
+    pub launch_template_id: Option<String>,
+    pub launch_template_name: Option<String>,
+    pub version: Option<String>,
+}
+
+impl LaunchTemplateSpecification {
+    pub fn launch_template_id(mut self, launch_template_id: &str) -> Self {
+        self.launch_template_id = Some(launch_template_id.into());
+        self
+    }
+
+    pub fn launch_template_name(mut self, launch_template_name: &str) -> Self {
+        self.launch_template_name = Some(launch_template_name.into());
+        self
+    }
+
+    // A version number, or "$Latest" and "$Default".
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceMetadataOptionsRequest {
+    pub http_endpoint: Option<String>,
+    pub http_protocol_ipv6: Option<String>,
+    pub http_put_response_hop_limit: Option<u32>,
+    pub http_tokens: Option<String>,
+    pub instance_metadata_tags: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceNetworkInterfaceSpecification {
+    pub associate_public_ip_address: Option<bool>,
     pub delete_on_termination: Option<bool>,
-    #[serde(rename = "device")]
-    pub device: Option<String>,
-    #[serde(rename = "instanceId")]
-    pub instance_id: Option<String>,
-    #[serde(rename = "instanceOwningService")]
-    pub instance_owning_service: Option<Status>,
-    #[serde(rename = "status")]
-    pub status: Option<String>,
-    #[serde(rename = "volumeId")]
-    pub volume_id: Option<String>,
+    pub description: Option<String>,
+    pub device_index: Option<u32>,
+    #[serde(rename = "SecurityGroupId")]
+    pub groups: Option<Vec<String>>,
+    pub network_card_index: Option<u32>,
+    pub network_interface_id: Option<String>,
+    pub private_ip_address: Option<String>,
+    pub secondary_private_ip_address_count: Option<u32>,
+    pub subnet_id: Option<String>,
 }
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Status {
-    Attaching,
-    Attached,
-    Detaching,
-    Detached,
-    Busy,
+pub struct TagSpecification {
+    pub resource_type: String,
+    #[serde(rename = "Tag")]
+    pub tags: Vec<Tag>,
+}
 
-    #[serde(other)]
-    #[default]
-    Unknown,
+impl TagSpecification {
+    pub fn new(resource_type: &str, tags: Vec<Tag>) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            tags,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RunInstancesOutput {
+    #[serde(rename = "groupSet")]
+    pub groups: Option<GroupIdentifierSet>,
+    #[serde(rename = "instancesSet")]
+    pub instances: Option<InstanceSet>,
+    #[serde(rename = "ownerId")]
+    pub owner_id: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(rename = "requesterId")]
+    pub requester_id: Option<String>,
+    #[serde(rename = "reservationId")]
+    pub reservation_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StartInstancesInput {
+    #[serde(rename = "InstanceId")]
+    pub instance_ids: Vec<String>,
+}
+
+impl StartInstancesInput {
+    pub fn instance_ids(mut self, instance_ids: Vec<String>) -> Self {
+        self.instance_ids = instance_ids;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StartInstancesOutput {
+    #[serde(rename = "instancesSet")]
+    pub instances: Option<InstanceStateChangeSet>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StopInstancesInput {
+    pub force: Option<bool>,
+    pub hibernate: Option<bool>,
+    #[serde(rename = "InstanceId")]
+    pub instance_ids: Vec<String>,
+}
+
+impl StopInstancesInput {
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+
+    pub fn hibernate(mut self, hibernate: bool) -> Self {
+        self.hibernate = Some(hibernate);
+        self
+    }
+
+    pub fn instance_ids(mut self, instance_ids: Vec<String>) -> Self {
+        self.instance_ids = instance_ids;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StopInstancesOutput {
+    #[serde(rename = "instancesSet")]
+    pub instances: Option<InstanceStateChangeSet>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TerminateInstancesInput {
+    #[serde(rename = "InstanceId")]
+    pub instance_ids: Vec<String>,
+}
+
+impl TerminateInstancesInput {
+    pub fn instance_ids(mut self, instance_ids: Vec<String>) -> Self {
+        self.instance_ids = instance_ids;
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TerminateInstancesOutput {
+    #[serde(rename = "instancesSet")]
+    pub instances: Option<InstanceStateChangeSet>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceStateChangeSet {
+    #[serde(rename = "item")]
+    pub items: Option<Vec<InstanceStateChange>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InstanceStateChange {
+    #[serde(rename = "currentState")]
+    pub current_state: Option<InstanceState>,
+    #[serde(rename = "instanceId")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "previousState")]
+    pub previous_state: Option<InstanceState>,
 }
 
 #[skip_serializing_none]
@@ -382,3 +6255,336 @@ pub struct ApiError {
     #[serde(rename = "Message")]
     pub message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_describe_instances() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DescribeInstancesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>8f7724cf-496f-496e-8fe3-example</requestId>
+    <reservationSet>
+        <item>
+            <reservationId>r-1234567890abcdef0</reservationId>
+            <ownerId>123456789012</ownerId>
+            <groupSet/>
+            <instancesSet>
+                <item>
+                    <instanceId>i-1234567890abcdef0</instanceId>
+                    <imageId>ami-bff32ccc</imageId>
+                    <instanceState>
+                        <code>16</code>
+                        <name>running</name>
+                    </instanceState>
+                    <privateDnsName>ip-192-168-1-88.eu-west-1.compute.internal</privateDnsName>
+                    <dnsName>ec2-54-194-252-215.eu-west-1.compute.amazonaws.com</dnsName>
+                    <instanceType>t2.micro</instanceType>
+                    <launchTime>2018-05-08T16:46:19.000Z</launchTime>
+                    <subnetId>subnet-56f5f633</subnetId>
+                    <vpcId>vpc-11112222</vpcId>
+                    <privateIpAddress>192.168.1.88</privateIpAddress>
+                    <ipAddress>54.194.252.215</ipAddress>
+                    <sourceDestCheck>true</sourceDestCheck>
+                    <groupSet>
+                        <item>
+                            <groupId>sg-e4076980</groupId>
+                            <groupName>SecurityGroup1</groupName>
+                        </item>
+                    </groupSet>
+                    <tagSet>
+                        <item>
+                            <key>Name</key>
+                            <value>Server_1</value>
+                        </item>
+                        <item>
+                            <key>Team</key>
+                            <value>core</value>
+                        </item>
+                    </tagSet>
+                </item>
+            </instancesSet>
+        </item>
+    </reservationSet>
+    <nextToken>token</nextToken>
+</DescribeInstancesResponse>"#;
+        let output: DescribeInstancesOutput = serde_xml_rs::from_str(body).unwrap();
+        assert_eq!(output.next_token.as_deref(), Some("token"));
+
+        let reservations = output.reservations.unwrap().items.unwrap();
+        assert_eq!(reservations.len(), 1);
+        assert_eq!(
+            reservations[0].reservation_id.as_deref(),
+            Some("r-1234567890abcdef0")
+        );
+
+        let instances = reservations[0].instances.clone().unwrap().items.unwrap();
+        assert_eq!(instances.len(), 1);
+        let instance = &instances[0];
+        assert_eq!(instance.instance_id.as_deref(), Some("i-1234567890abcdef0"));
+        let state = instance.state.clone().unwrap();
+        assert_eq!(state.code, Some(16));
+        assert!(matches!(state.name, Some(InstanceStateName::Running)));
+        assert_eq!(
+            instance.launch_time.unwrap().to_rfc3339(),
+            "2018-05-08T16:46:19+00:00"
+        );
+        assert_eq!(
+            instance.public_ip_address.as_deref(),
+            Some("54.194.252.215")
+        );
+        assert_eq!(instance.source_dest_check, Some(true));
+
+        let groups = instance.groups.clone().unwrap().items.unwrap();
+        assert_eq!(groups[0].group_id.as_deref(), Some("sg-e4076980"));
+
+        let tags = instance.tags.clone().unwrap().items.unwrap();
+        let tags = tags
+            .iter()
+            .map(|t| (t.key.as_deref().unwrap(), t.value.as_deref().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec![("Name", "Server_1"), ("Team", "core")]);
+    }
+
+    #[test]
+    fn parses_describe_volumes() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DescribeVolumesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>59dbff89-35bd-4eac-99ed-be587example</requestId>
+    <volumeSet>
+        <item>
+            <volumeId>vol-1234567890abcdef0</volumeId>
+            <size>80</size>
+            <availabilityZone>us-east-1a</availabilityZone>
+            <status>in-use</status>
+            <createTime>2013-12-18T22:35:00.084Z</createTime>
+            <attachmentSet>
+                <item>
+                    <volumeId>vol-1234567890abcdef0</volumeId>
+                    <instanceId>i-1234567890abcdef0</instanceId>
+                    <device>/dev/sdh</device>
+                    <status>attached</status>
+                    <attachTime>2013-12-18T22:35:00.000Z</attachTime>
+                    <deleteOnTermination>true</deleteOnTermination>
+                </item>
+            </attachmentSet>
+            <volumeType>gp3</volumeType>
+            <iops>3000</iops>
+            <encrypted>false</encrypted>
+            <multiAttachEnabled>false</multiAttachEnabled>
+        </item>
+    </volumeSet>
+</DescribeVolumesResponse>"#;
+        let output: DescribeVolumesOutput = serde_xml_rs::from_str(body).unwrap();
+        let volumes = output.volumes.unwrap().items.unwrap();
+        assert_eq!(volumes.len(), 1);
+        let volume = &volumes[0];
+        assert_eq!(volume.volume_id.as_deref(), Some("vol-1234567890abcdef0"));
+        assert_eq!(volume.size, Some(80));
+        assert_eq!(volume.state, Some(VolumeState::InUse));
+        assert_eq!(volume.volume_type, Some(VolumeType::Gp3));
+        assert_eq!(
+            volume.create_time.unwrap().to_rfc3339(),
+            "2013-12-18T22:35:00.084+00:00"
+        );
+
+        let attachments = volume.attachments.clone().unwrap().items.unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(
+            attachments[0].instance_id.as_deref(),
+            Some("i-1234567890abcdef0")
+        );
+        assert_eq!(attachments[0].device.as_deref(), Some("/dev/sdh"));
+        assert_eq!(attachments[0].status, Some(AttachmentStatus::Attached));
+        assert_eq!(attachments[0].delete_on_termination, Some(true));
+    }
+
+    #[test]
+    fn parses_describe_images() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DescribeImagesResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>59dbff89-35bd-4eac-99ed-be587example</requestId>
+    <imagesSet>
+        <item>
+            <imageId>ami-1234567890abcdef0</imageId>
+            <imageLocation>123456789012/my-image</imageLocation>
+            <imageState>available</imageState>
+            <imageOwnerId>123456789012</imageOwnerId>
+            <creationDate>2022-01-03T18:29:03.000Z</creationDate>
+            <isPublic>false</isPublic>
+            <architecture>x86_64</architecture>
+            <imageType>machine</imageType>
+            <name>my-image</name>
+            <rootDeviceType>ebs</rootDeviceType>
+            <rootDeviceName>/dev/xvda</rootDeviceName>
+            <blockDeviceMapping>
+                <item>
+                    <deviceName>/dev/xvda</deviceName>
+                    <ebs>
+                        <snapshotId>snap-1234567890abcdef0</snapshotId>
+                        <volumeSize>8</volumeSize>
+                        <deleteOnTermination>true</deleteOnTermination>
+                        <volumeType>gp2</volumeType>
+                        <encrypted>false</encrypted>
+                    </ebs>
+                </item>
+            </blockDeviceMapping>
+            <virtualizationType>hvm</virtualizationType>
+            <tagSet>
+                <item>
+                    <key>Name</key>
+                    <value>base</value>
+                </item>
+            </tagSet>
+            <hypervisor>xen</hypervisor>
+            <enaSupport>true</enaSupport>
+        </item>
+    </imagesSet>
+</DescribeImagesResponse>"#;
+        let output: DescribeImagesOutput = serde_xml_rs::from_str(body).unwrap();
+        let images = output.images.unwrap().items.unwrap();
+        assert_eq!(images.len(), 1);
+        let image = &images[0];
+        assert_eq!(image.image_id.as_deref(), Some("ami-1234567890abcdef0"));
+        assert_eq!(image.state, Some(ImageState::Available));
+        assert_eq!(image.owner_id.as_deref(), Some("123456789012"));
+        assert_eq!(
+            image.creation_date.unwrap().to_rfc3339(),
+            "2022-01-03T18:29:03+00:00"
+        );
+        assert_eq!(image.is_public, Some(false));
+        assert_eq!(image.ena_support, Some(true));
+
+        let mappings = image.block_device_mappings.clone().unwrap().items.unwrap();
+        assert_eq!(mappings[0].device_name.as_deref(), Some("/dev/xvda"));
+        let ebs = mappings[0].ebs.clone().unwrap();
+        assert_eq!(ebs.snapshot_id.as_deref(), Some("snap-1234567890abcdef0"));
+        assert_eq!(ebs.volume_size, Some(8));
+
+        let tags = image.tags.clone().unwrap().items.unwrap();
+        assert_eq!(tags[0].value.as_deref(), Some("base"));
+    }
+
+    #[test]
+    fn parses_get_console_output() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<GetConsoleOutputResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>59dbff89-35bd-4eac-99ed-be587example</requestId>
+    <instanceId>i-1234567890abcdef0</instanceId>
+    <timestamp>2010-10-14T01:12:41.000Z</timestamp>
+    <output>TGludXggdmVyc2lvbiA1LjEwDQpsb2dpbjog/w==</output>
+</GetConsoleOutputResponse>"#;
+        let output: GetConsoleOutputOutput = serde_xml_rs::from_str(body).unwrap();
+        assert_eq!(output.instance_id.as_deref(), Some("i-1234567890abcdef0"));
+        assert_eq!(
+            output.timestamp.unwrap().to_rfc3339(),
+            "2010-10-14T01:12:41+00:00"
+        );
+        // Invalid UTF-8 in the console output is replaced rather than rejected.
+        assert_eq!(
+            output.output.as_deref(),
+            Some("Linux version 5.10\r\nlogin: \u{fffd}")
+        );
+
+        let body = r#"<GetConsoleOutputResponse>
+    <instanceId>i-1234567890abcdef0</instanceId>
+</GetConsoleOutputResponse>"#;
+        let output: GetConsoleOutputOutput = serde_xml_rs::from_str(body).unwrap();
+        assert_eq!(output.output, None);
+    }
+
+    #[test]
+    fn parses_describe_spot_instance_requests() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DescribeSpotInstanceRequestsResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>d9da716e-1b7b-4c8b-a2e1-example</requestId>
+    <spotInstanceRequestSet>
+        <item>
+            <spotInstanceRequestId>sir-1a2b3c4d</spotInstanceRequestId>
+            <spotPrice>0.050000</spotPrice>
+            <type>one-time</type>
+            <state>active</state>
+            <status>
+                <code>fulfilled</code>
+                <updateTime>2019-10-16T17:55:30.000Z</updateTime>
+                <message>Your spot request is fulfilled.</message>
+            </status>
+            <launchSpecification>
+                <imageId>ami-1a2b3c4d</imageId>
+                <keyName>my-key-pair</keyName>
+                <groupSet>
+                    <item>
+                        <groupId>sg-1a2b3c4d</groupId>
+                        <groupName>websrv</groupName>
+                    </item>
+                </groupSet>
+                <instanceType>m5.large</instanceType>
+                <placement>
+                    <availabilityZone>us-west-2a</availabilityZone>
+                </placement>
+                <subnetId>subnet-1a2b3c4d</subnetId>
+            </launchSpecification>
+            <instanceId>i-1234567890abcdef0</instanceId>
+            <createTime>2019-10-16T17:53:49.000Z</createTime>
+            <productDescription>Linux/UNIX</productDescription>
+            <tagSet>
+                <item>
+                    <key>Name</key>
+                    <value>worker</value>
+                </item>
+            </tagSet>
+            <launchedAvailabilityZone>us-west-2a</launchedAvailabilityZone>
+            <instanceInterruptionBehavior>terminate</instanceInterruptionBehavior>
+        </item>
+    </spotInstanceRequestSet>
+</DescribeSpotInstanceRequestsResponse>"#;
+        let output: DescribeSpotInstanceRequestsOutput = serde_xml_rs::from_str(body).unwrap();
+        let requests = output.spot_instance_requests.unwrap().items.unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(
+            request.spot_instance_request_id.as_deref(),
+            Some("sir-1a2b3c4d")
+        );
+        assert_eq!(request.request_type.as_deref(), Some("one-time"));
+        assert_eq!(request.state, Some(SpotInstanceState::Active));
+        assert_eq!(request.instance_id.as_deref(), Some("i-1234567890abcdef0"));
+        assert_eq!(
+            request.create_time.unwrap().to_rfc3339(),
+            "2019-10-16T17:53:49+00:00"
+        );
+
+        let status = request.status.clone().unwrap();
+        assert_eq!(status.code.as_deref(), Some("fulfilled"));
+
+        let specification = request.launch_specification.clone().unwrap();
+        assert_eq!(specification.image_id.as_deref(), Some("ami-1a2b3c4d"));
+        assert_eq!(specification.instance_type.as_deref(), Some("m5.large"));
+        let groups = specification.groups.unwrap().items.unwrap();
+        assert_eq!(groups[0].group_id.as_deref(), Some("sg-1a2b3c4d"));
+
+        let tags = request.tags.clone().unwrap().items.unwrap();
+        assert_eq!(tags[0].value.as_deref(), Some("worker"));
+    }
+
+    #[test]
+    fn parses_attach_volume() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AttachVolumeResponse xmlns="http://ec2.amazonaws.com/doc/2016-11-15/">
+    <requestId>59dbff89-35bd-4eac-99ed-be587example</requestId>
+    <volumeId>vol-1234567890abcdef0</volumeId>
+    <instanceId>i-1234567890abcdef0</instanceId>
+    <instanceOwningService>ecs.amazonaws.com</instanceOwningService>
+    <device>/dev/sdh</device>
+    <status>attaching</status>
+    <attachTime>2008-05-07T11:51:50.000Z</attachTime>
+</AttachVolumeResponse>"#;
+        let output: AttachVolumeOutput = serde_xml_rs::from_str(body).unwrap();
+        assert_eq!(output.status, Some(AttachmentStatus::Attaching));
+        assert_eq!(
+            output.instance_owning_service.as_deref(),
+            Some("ecs.amazonaws.com")
+        );
+    }
+}
@@ -1,6 +1,8 @@
 pub mod ec2;
 pub mod imds;
+mod query;
 pub mod request;
 pub mod s3;
 pub mod secretsmanager;
 pub mod ssm;
+pub mod waiter;
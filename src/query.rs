@@ -0,0 +1,537 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{
+    ser::{self, Impossible},
+    Serialize,
+};
+use serde_xml_rs::Error;
+
+type Result<T> = std::result::Result<T, Error>;
+
+// Serializes an input struct into AWS Query protocol parameters. Field names
+// are converted to PascalCase unless renamed, sequences are numbered from 1 and
+// maps become numbered Key and Value pairs. A field named "item", as used by
+// the EC2 XML list wrappers, adds no name of its own so output types can be
+// reused as inputs.
+pub(crate) fn to_params<T: Serialize>(value: &T) -> Result<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    value.serialize(ParamSerializer {
+        params: &mut params,
+        prefix: String::new(),
+    })?;
+    Ok(params)
+}
+
+fn unsupported<T>(operation: &str) -> Result<T> {
+    Err(Error::UnsupportedOperation {
+        operation: operation.into(),
+    })
+}
+
+fn member_name(key: &str) -> String {
+    key.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    match (prefix.is_empty(), name.is_empty()) {
+        (true, _) => name.into(),
+        (_, true) => prefix.into(),
+        _ => format!("{}.{}", prefix, name),
+    }
+}
+
+struct ParamSerializer<'a> {
+    params: &'a mut Vec<(String, String)>,
+    prefix: String,
+}
+
+impl ParamSerializer<'_> {
+    fn value(self, value: String) -> Result<()> {
+        if self.prefix.is_empty() {
+            return unsupported("root value");
+        }
+        self.params.push((self.prefix, value));
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for ParamSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.value(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.value(v.into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.value(BASE64.encode(v))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.value(variant.into())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        unsupported("newtype variant")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        if self.prefix.is_empty() {
+            return unsupported("root sequence");
+        }
+        Ok(SeqSerializer {
+            params: self.params,
+            prefix: self.prefix,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        unsupported("tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unsupported("tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unsupported("tuple variant")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        if self.prefix.is_empty() {
+            return unsupported("root map");
+        }
+        Ok(MapSerializer {
+            params: self.params,
+            prefix: self.prefix,
+            index: 0,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(StructSerializer {
+            params: self.params,
+            prefix: self.prefix,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unsupported("struct variant")
+    }
+}
+
+struct SeqSerializer<'a> {
+    params: &'a mut Vec<(String, String)>,
+    prefix: String,
+    index: usize,
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.index += 1;
+        value.serialize(ParamSerializer {
+            params: self.params,
+            prefix: format!("{}.{}", self.prefix, self.index),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct MapSerializer<'a> {
+    params: &'a mut Vec<(String, String)>,
+    prefix: String,
+    index: usize,
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        self.index += 1;
+        key.serialize(ParamSerializer {
+            params: self.params,
+            prefix: format!("{}.{}.Key", self.prefix, self.index),
+        })
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(ParamSerializer {
+            params: self.params,
+            prefix: format!("{}.{}.Value", self.prefix, self.index),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct StructSerializer<'a> {
+    params: &'a mut Vec<(String, String)>,
+    prefix: String,
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let name = match key {
+            "item" => String::new(),
+            _ => member_name(key),
+        };
+        value.serialize(ParamSerializer {
+            params: self.params,
+            prefix: join(&self.prefix, &name),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::Serializer;
+    use serde_with::skip_serializing_none;
+
+    use super::*;
+
+    fn params<T: Serialize>(value: &T) -> Vec<(String, String)> {
+        to_params(value).unwrap()
+    }
+
+    fn expected(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[derive(Serialize)]
+    struct Tag {
+        key: String,
+        value: String,
+    }
+
+    #[derive(Serialize)]
+    struct Wrapper<T> {
+        item: T,
+    }
+
+    #[derive(Serialize)]
+    struct BooleanValue {
+        value: bool,
+    }
+
+    struct Bytes(&'static [u8]);
+
+    impl Serialize for Bytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    #[test]
+    fn numbers_sequences_from_one() {
+        #[derive(Serialize)]
+        struct Input {
+            #[serde(rename = "InstanceId")]
+            instance_ids: Vec<String>,
+        }
+        let input = Input {
+            instance_ids: vec!["i-1".into(), "i-2".into()],
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[("InstanceId.1", "i-1"), ("InstanceId.2", "i-2")])
+        );
+    }
+
+    #[test]
+    fn flattens_item_fields() {
+        #[derive(Serialize)]
+        struct Input {
+            tag_set: Wrapper<Vec<Tag>>,
+        }
+        let input = Input {
+            tag_set: Wrapper {
+                item: vec![Tag {
+                    key: "Name".into(),
+                    value: "web".into(),
+                }],
+            },
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[("TagSet.1.Key", "Name"), ("TagSet.1.Value", "web")])
+        );
+    }
+
+    #[test]
+    fn numbers_map_entries() {
+        #[derive(Serialize)]
+        struct Input {
+            attributes: BTreeMap<String, String>,
+        }
+        let input = Input {
+            attributes: [("a", "1"), ("b", "2")]
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[
+                ("Attributes.1.Key", "a"),
+                ("Attributes.1.Value", "1"),
+                ("Attributes.2.Key", "b"),
+                ("Attributes.2.Value", "2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn nests_structs() {
+        #[derive(Serialize)]
+        struct Placement {
+            availability_zone: String,
+            #[serde(rename = "tenancy")]
+            tenancy: String,
+        }
+        #[derive(Serialize)]
+        struct Input {
+            placement: Placement,
+            #[serde(rename = "TagSpecification")]
+            tag_specifications: Vec<Wrapper<Tag>>,
+        }
+        let input = Input {
+            placement: Placement {
+                availability_zone: "us-east-1a".into(),
+                tenancy: "default".into(),
+            },
+            tag_specifications: vec![Wrapper {
+                item: Tag {
+                    key: "k".into(),
+                    value: "v".into(),
+                },
+            }],
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[
+                ("Placement.AvailabilityZone", "us-east-1a"),
+                ("Placement.Tenancy", "default"),
+                ("TagSpecification.1.Key", "k"),
+                ("TagSpecification.1.Value", "v"),
+            ])
+        );
+    }
+
+    #[test]
+    fn writes_value_wrappers() {
+        #[derive(Serialize)]
+        struct Input {
+            disable_api_stop: BooleanValue,
+            #[serde(rename = "DisableApiTermination.Value")]
+            disable_api_termination: bool,
+        }
+        let input = Input {
+            disable_api_stop: BooleanValue { value: true },
+            disable_api_termination: false,
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[
+                ("DisableApiStop.Value", "true"),
+                ("DisableApiTermination.Value", "false"),
+            ])
+        );
+    }
+
+    #[test]
+    fn encodes_bytes_as_base64() {
+        #[derive(Serialize)]
+        struct Input {
+            user_data: Bytes,
+        }
+        let input = Input {
+            user_data: Bytes(b"#!/bin/sh"),
+        };
+        assert_eq!(params(&input), expected(&[("UserData", "IyEvYmluL3No")]));
+    }
+
+    #[test]
+    fn skips_none() {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Input {
+            dry_run: Option<bool>,
+            max_results: Option<u32>,
+            next_token: Option<String>,
+        }
+        let input = Input {
+            dry_run: None,
+            max_results: Some(5),
+            next_token: None,
+        };
+        assert_eq!(params(&input), expected(&[("MaxResults", "5")]));
+
+        // Without skip_serializing_none the field is still left out.
+        #[derive(Serialize)]
+        struct Plain {
+            next_token: Option<String>,
+        }
+        assert!(params(&Plain { next_token: None }).is_empty());
+    }
+
+    #[test]
+    fn writes_rfc3339_timestamps() {
+        #[derive(Serialize)]
+        struct Input {
+            start_time: DateTime<Utc>,
+        }
+        let input = Input {
+            start_time: Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+        };
+        assert_eq!(
+            params(&input),
+            expected(&[("StartTime", "2024-01-02T03:04:05Z")])
+        );
+    }
+
+    #[test]
+    fn rejects_root_values() {
+        assert!(to_params(&"value").is_err());
+        assert!(to_params(&vec![1, 2]).is_err());
+    }
+}
@@ -5,10 +5,11 @@ use std::{
 
 use aws_sigv4::{
     http_request::{
-        sign, PayloadChecksumKind, SignableBody, SignableRequest, SigningError,
-        SigningInstructions, SigningSettings,
+        sign, PayloadChecksumKind, PercentEncodingMode, SignableBody, SignableRequest,
+        SignatureLocation, SigningError, SigningInstructions, SigningSettings,
+        UriPathNormalizationMode,
     },
-    sign::v4::SigningParams,
+    sign::v4::{calculate_signature, generate_signing_key, SigningParams},
 };
 use aws_smithy_runtime_api::client::identity::Identity;
 use crossbeam::utils::Backoff;
@@ -56,10 +57,45 @@ pub fn sign_request(
     identity: &Identity,
     region: &str,
     service: &str,
+) -> Result<Request> {
+    sign_with_body(
+        request,
+        SignableBody::Bytes(body),
+        identity,
+        region,
+        service,
+    )
+}
+
+// Signs a request whose body is streamed, so the payload is left out of the
+// signature.
+pub fn sign_streaming_request(
+    request: Request,
+    identity: &Identity,
+    region: &str,
+    service: &str,
+) -> Result<Request> {
+    sign_with_body(
+        request,
+        SignableBody::UnsignedPayload,
+        identity,
+        region,
+        service,
+    )
+}
+
+fn sign_with_body(
+    request: Request,
+    body: SignableBody,
+    identity: &Identity,
+    region: &str,
+    service: &str,
 ) -> Result<Request> {
     let mut signing_settings = SigningSettings::default();
     if service == "s3" {
         signing_settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+        signing_settings.percent_encoding_mode = PercentEncodingMode::Single;
+        signing_settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
     }
     let signing_params = SigningParams::builder()
         .identity(identity)
@@ -75,17 +111,55 @@ pub fn sign_request(
         let value = request.header(name).unwrap_or("");
         (name.as_ref(), value)
     });
-    let signable_request = SignableRequest::new(
-        request.method(),
-        request.url(),
-        headers,
-        SignableBody::Bytes(body),
-    )?;
+    let signable_request = SignableRequest::new(request.method(), request.url(), headers, body)?;
     let signing_output = sign(signable_request, &signing_params)?;
     let (signing_instructions, _) = signing_output.into_parts();
     Ok(update_request(request, signing_instructions))
 }
 
+pub fn presign_url(
+    url: &str,
+    identity: &Identity,
+    region: &str,
+    service: &str,
+    expires_in: Duration,
+) -> Result<String> {
+    let mut signing_settings = SigningSettings::default();
+    signing_settings.signature_location = SignatureLocation::QueryParams;
+    signing_settings.expires_in = Some(expires_in);
+    let signing_params = SigningParams::builder()
+        .identity(identity)
+        .region(region)
+        .name(service)
+        .time(SystemTime::now())
+        .settings(signing_settings)
+        .build()
+        .unwrap()
+        .into();
+    let signable_request =
+        SignableRequest::new("GET", url, std::iter::empty(), SignableBody::Bytes(&[]))?;
+    let signing_output = sign(signable_request, &signing_params)?;
+    let (signing_instructions, _) = signing_output.into_parts();
+    let (_, params) = signing_instructions.into_parts();
+    let mut serializer = form_urlencoded::Serializer::new(String::new());
+    for (name, value) in params {
+        serializer.append_pair(name, &value);
+    }
+    let separator = if url.contains('?') { '&' } else { '?' };
+    Ok(format!("{}{}{}", url, separator, serializer.finish()))
+}
+
+pub fn sign_string(
+    string_to_sign: &str,
+    secret_access_key: &str,
+    time: SystemTime,
+    region: &str,
+    service: &str,
+) -> String {
+    let signing_key = generate_signing_key(secret_access_key, time, region, service);
+    calculate_signature(signing_key, string_to_sign.as_bytes())
+}
+
 fn update_request(mut request: Request, instructions: SigningInstructions) -> Request {
     let (headers, params) = instructions.into_parts();
     for header in headers {
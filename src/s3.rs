@@ -2,22 +2,42 @@ use std::{
     error,
     fmt::{Debug, Display, Formatter},
     io::{self, Read},
+    time::Duration,
 };
 
 use aws_credential_types::Credentials;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use chrono::{DateTime, Utc};
+use md5::{Digest, Md5};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use ureq::Response;
 
-use crate::request::{self, sign_request, with_retry};
+use crate::{
+    request::{self, sign_request, with_retry},
+    waiter::Waiter,
+};
+
+pub mod presigned_post;
+pub mod select;
+pub mod sync;
+mod xml;
 
 const SERVICE_NAME: &str = "s3";
 
+const KEY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
 type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
+    Glob(glob::PatternError),
     Io(io::Error),
     Request(request::Error),
     S3(ErrorBody),
@@ -29,6 +49,7 @@ impl error::Error for Error {}
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::Glob(e) => write!(f, "glob pattern error: {}", e),
             Self::Io(e) => write!(f, "io error: {}", e),
             Self::Request(e) => write!(f, "http request error: {}", e),
             Self::S3(eb) => write!(f, "s3 error: {}", eb.message),
@@ -37,6 +58,12 @@ impl Display for Error {
     }
 }
 
+impl From<glob::PatternError> for Error {
+    fn from(err: glob::PatternError) -> Self {
+        Error::Glob(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Self {
         Error::Io(err)
@@ -46,9 +73,23 @@ impl From<io::Error> for Error {
 impl From<request::Error> for Error {
     fn from(err: request::Error) -> Self {
         match err {
-            request::Error::Api(_, response) => {
-                let body = response.into_reader();
-                match serde_xml_rs::from_reader(body) {
+            request::Error::Api(status, response) => {
+                let request_id = response.header("x-amz-request-id").map(Into::into);
+                let message = response.status_text().to_string();
+                let mut body = String::new();
+                if let Err(e) = response.into_reader().read_to_string(&mut body) {
+                    return Error::Io(e);
+                }
+                // HEAD responses have no error document, only a status code.
+                if body.trim().is_empty() {
+                    return Error::S3(ErrorBody {
+                        code: status_code(status),
+                        message,
+                        resource: None,
+                        request_id: request_id.unwrap_or_default(),
+                    });
+                }
+                match serde_xml_rs::from_str(&body) {
                     Ok(err_body) => Error::S3(err_body),
                     Err(e) => Error::Xml(e),
                 }
@@ -63,6 +104,30 @@ impl From<request::Error> for Error {
     }
 }
 
+impl Error {
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::S3(eb) => matches!(
+                eb.code.as_str(),
+                "NotFound" | "NoSuchBucket" | "NoSuchKey" | "NoSuchVersion"
+            ),
+            _ => false,
+        }
+    }
+}
+
+fn status_code(status: u16) -> String {
+    match status {
+        301 => "PermanentRedirect".into(),
+        304 => "NotModified".into(),
+        400 => "BadRequest".into(),
+        403 => "Forbidden".into(),
+        404 => "NotFound".into(),
+        412 => "PreconditionFailed".into(),
+        status => status.to_string(),
+    }
+}
+
 impl From<serde_xml_rs::Error> for Error {
     fn from(err: serde_xml_rs::Error) -> Self {
         Error::Xml(err)
@@ -83,6 +148,181 @@ impl Api {
         }
     }
 
+    pub fn list_buckets(&self, input: ListBucketsInput) -> Result<ListBucketsOutput> {
+        let mut req = ureq::get(&format!("{}/", self.service_url()));
+        if let Some(continuation_token) = input.continuation_token {
+            req = req.query("continuation-token", &continuation_token);
+        }
+        if let Some(max_buckets) = input.max_buckets {
+            req = req.query("max-buckets", &max_buckets.to_string());
+        }
+        if let Some(prefix) = input.prefix {
+            req = req.query("prefix", &prefix);
+        }
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    pub fn create_bucket(&self, input: CreateBucketInput) -> Result<CreateBucketOutput> {
+        let location_constraint = input
+            .location_constraint
+            .unwrap_or_else(|| self.region.clone());
+        // The bucket has to be created through the endpoint of its own region.
+        let api = Api::new(&location_constraint, self.credentials.clone());
+        let req = ureq::put(&format!("{}/", api.url(&input.bucket)));
+        // Buckets in us-east-1 must be created without a location constraint.
+        let body = if location_constraint == "us-east-1" {
+            Vec::new()
+        } else {
+            let configuration = CreateBucketConfiguration {
+                location_constraint: Some(location_constraint),
+            };
+            xml::to_string(&configuration)?.into_bytes()
+        };
+        api.send(req, &body).map(|response| CreateBucketOutput {
+            location: response.header("Location").map(Into::into),
+        })
+    }
+
+    pub fn delete_bucket(&self, input: DeleteBucketInput) -> Result<DeleteBucketOutput> {
+        let req = ureq::delete(&format!("{}/", self.url(&input.bucket)));
+        self.send(req, &[]).map(|_| DeleteBucketOutput {})
+    }
+
+    pub fn head_bucket(&self, input: HeadBucketInput) -> Result<HeadBucketOutput> {
+        let req = ureq::head(&format!("{}/", self.url(&input.bucket)));
+        self.send(req, &[]).map(|response| HeadBucketOutput {
+            access_point_alias: response
+                .header("x-amz-access-point-alias")
+                .map(|v| v.eq_ignore_ascii_case("true")),
+            bucket_region: response.header("x-amz-bucket-region").map(Into::into),
+        })
+    }
+
+    pub fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningInput,
+    ) -> Result<GetBucketVersioningOutput> {
+        let mut req = ureq::get(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("versioning", "");
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    pub fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningInput,
+    ) -> Result<PutBucketVersioningOutput> {
+        let mut req = ureq::put(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("versioning", "");
+        let body = xml::to_string(&input.versioning_configuration)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|_| PutBucketVersioningOutput {})
+    }
+
+    pub fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationInput,
+    ) -> Result<GetBucketLifecycleConfigurationOutput> {
+        let mut req = ureq::get(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("lifecycle", "");
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    pub fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationInput,
+    ) -> Result<PutBucketLifecycleConfigurationOutput> {
+        let mut req = ureq::put(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("lifecycle", "");
+        let body = xml::to_string(&input.lifecycle_configuration)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|_| PutBucketLifecycleConfigurationOutput {})
+    }
+
+    pub fn get_bucket_policy(&self, input: GetBucketPolicyInput) -> Result<GetBucketPolicyOutput> {
+        let mut req = ureq::get(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("policy", "");
+        self.send(req, &[]).and_then(|response| {
+            let policy = response.into_string()?;
+            Ok(GetBucketPolicyOutput { policy })
+        })
+    }
+
+    pub fn put_bucket_policy(&self, input: PutBucketPolicyInput) -> Result<PutBucketPolicyOutput> {
+        let mut req = ureq::put(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("policy", "");
+        req = req.set("Content-Type", "application/json");
+        self.send(req, input.policy.as_bytes())
+            .map(|_| PutBucketPolicyOutput {})
+    }
+
+    pub fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockInput,
+    ) -> Result<GetPublicAccessBlockOutput> {
+        let mut req = ureq::get(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("publicAccessBlock", "");
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let public_access_block_configuration = serde_xml_rs::from_reader(body)?;
+            Ok(GetPublicAccessBlockOutput {
+                public_access_block_configuration,
+            })
+        })
+    }
+
+    pub fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockInput,
+    ) -> Result<PutPublicAccessBlockOutput> {
+        let mut req = ureq::put(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("publicAccessBlock", "");
+        let body = xml::to_string(&input.public_access_block_configuration)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|_| PutPublicAccessBlockOutput {})
+    }
+
+    pub fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionInput,
+    ) -> Result<GetBucketEncryptionOutput> {
+        let mut req = ureq::get(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("encryption", "");
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let server_side_encryption_configuration = serde_xml_rs::from_reader(body)?;
+            Ok(GetBucketEncryptionOutput {
+                server_side_encryption_configuration,
+            })
+        })
+    }
+
+    pub fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionInput,
+    ) -> Result<PutBucketEncryptionOutput> {
+        let mut req = ureq::put(&format!("{}/", self.url(&input.bucket)));
+        req = req.query("encryption", "");
+        let body = xml::to_string(&input.server_side_encryption_configuration)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|_| PutBucketEncryptionOutput {})
+    }
+
     pub fn list_objects_v2(&self, input: ListObjectsV2Input) -> Result<ListObjectsV2Output> {
         let url = &self.url(&input.bucket);
         let mut req = ureq::get(&format!("{}/", url));
@@ -93,7 +333,7 @@ impl Api {
         if let Some(prefix) = input.prefix {
             req = req.query("prefix", &prefix);
         }
-        self.send(req)
+        self.send(req, &[])
             .and_then(|response| {
                 let body = response.into_reader();
                 let output = serde_xml_rs::from_reader(body)?;
@@ -102,22 +342,241 @@ impl Api {
             .map_err(Into::into)
     }
 
-    pub fn get_object(&self, input: GetObjectInput) -> Result<GetObjectOutput> {
+    pub fn list_objects_v2_paginator(
+        &self,
+        input: ListObjectsV2Input,
+    ) -> ListObjectsV2Paginator<'_> {
+        ListObjectsV2Paginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn list_object_versions(
+        &self,
+        input: ListObjectVersionsInput,
+    ) -> Result<ListObjectVersionsOutput> {
         let url = &self.url(&input.bucket);
-        let req = ureq::get(&format!("{}/{}", url, input.key));
-        self.send(req)
+        let mut req = ureq::get(&format!("{}/", url));
+        req = req.query("versions", "");
+        if let Some(delimiter) = input.delimiter {
+            req = req.query("delimiter", &delimiter);
+        }
+        if let Some(key_marker) = input.key_marker {
+            req = req.query("key-marker", &key_marker);
+        }
+        if let Some(max_keys) = input.max_keys {
+            req = req.query("max-keys", &max_keys.to_string());
+        }
+        if let Some(prefix) = input.prefix {
+            req = req.query("prefix", &prefix);
+        }
+        if let Some(version_id_marker) = input.version_id_marker {
+            req = req.query("version-id-marker", &version_id_marker);
+        }
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    pub fn list_object_versions_paginator(
+        &self,
+        input: ListObjectVersionsInput,
+    ) -> ListObjectVersionsPaginator<'_> {
+        ListObjectVersionsPaginator {
+            api: self,
+            input,
+            done: false,
+        }
+    }
+
+    pub fn get_object(&self, input: GetObjectInput) -> Result<GetObjectOutput> {
+        let mut req = ureq::get(&self.object_url(&input.bucket, &input.key));
+        if let Some(version_id) = input.version_id {
+            req = req.query("versionId", &version_id);
+        }
+        self.send(req, &[])
             .and_then(|response| {
                 Ok(GetObjectOutput {
+                    delete_marker: delete_marker(&response),
+                    restore: response
+                        .header("x-amz-restore")
+                        .and_then(ObjectRestore::parse),
+                    storage_class: response.header("x-amz-storage-class").map(Into::into),
+                    version_id: response.header("x-amz-version-id").map(Into::into),
                     body: response.into_reader(),
                 })
             })
             .map_err(Into::into)
     }
 
-    fn send(&self, mut req: ureq::Request) -> Result<Response> {
+    pub fn head_object(&self, input: HeadObjectInput) -> Result<HeadObjectOutput> {
+        let mut req = ureq::head(&self.object_url(&input.bucket, &input.key));
+        if let Some(version_id) = input.version_id {
+            req = req.query("versionId", &version_id);
+        }
+        self.send(req, &[]).map(|response| HeadObjectOutput {
+            content_length: response
+                .header("Content-Length")
+                .and_then(|v| v.parse().ok()),
+            content_type: response.header("Content-Type").map(Into::into),
+            delete_marker: delete_marker(&response),
+            e_tag: response.header("ETag").map(Into::into),
+            last_modified: response
+                .header("Last-Modified")
+                .and_then(|v| DateTime::parse_from_rfc2822(v).ok())
+                .map(Into::into),
+            restore: response
+                .header("x-amz-restore")
+                .and_then(ObjectRestore::parse),
+            storage_class: response.header("x-amz-storage-class").map(Into::into),
+            version_id: response.header("x-amz-version-id").map(Into::into),
+        })
+    }
+
+    pub fn restore_object(&self, input: RestoreObjectInput) -> Result<RestoreObjectOutput> {
+        let mut req = ureq::post(&self.object_url(&input.bucket, &input.key));
+        req = req.query("restore", "");
+        if let Some(version_id) = input.version_id {
+            req = req.query("versionId", &version_id);
+        }
+        let body = xml::to_string(&input.restore_request)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|response| RestoreObjectOutput {
+                already_restored: response.status() == 200,
+            })
+    }
+
+    pub fn wait_until_object_restored(
+        &self,
+        input: HeadObjectInput,
+        delay: Duration,
+        timeout: Duration,
+    ) -> Result<HeadObjectOutput> {
+        Waiter::new(&format!("restore of {}", input.key))
+            .min_delay(delay)
+            .max_delay(delay)
+            .timeout(timeout)
+            .success(|output: &HeadObjectOutput| {
+                restore_progress(output) == RestoreProgress::Restored
+            })
+            // An archived object without a restore header will never become
+            // readable, so there is nothing to wait for.
+            .failure(|output| restore_progress(output) == RestoreProgress::NotRequested)
+            .wait(|| self.head_object(input.clone()))
+    }
+
+    pub fn copy_object(&self, input: CopyObjectInput) -> Result<CopyObjectOutput> {
+        let mut req = ureq::put(&self.object_url(&input.bucket, &input.key));
+        let copy_source = copy_source(
+            &input.source_bucket,
+            &input.source_key,
+            input.source_version_id.as_deref(),
+        );
+        req = req.set("x-amz-copy-source", &copy_source);
+        self.send(req, &[]).and_then(|response| {
+            let copy_source_version_id = response
+                .header("x-amz-copy-source-version-id")
+                .map(Into::into);
+            let version_id = response.header("x-amz-version-id").map(Into::into);
+            let mut body = String::new();
+            response.into_reader().read_to_string(&mut body)?;
+            // A copy can fail after S3 has already sent a 200 status, in which
+            // case the body is an error document instead of a CopyObjectResult.
+            if is_error_document(&body) {
+                return Err(Error::S3(serde_xml_rs::from_str(&body)?));
+            }
+            let copy_object_result = serde_xml_rs::from_str(&body)?;
+            Ok(CopyObjectOutput {
+                copy_object_result,
+                copy_source_version_id,
+                version_id,
+            })
+        })
+    }
+
+    pub fn put_object(&self, input: PutObjectInput) -> Result<PutObjectOutput> {
+        let mut req = ureq::put(&self.object_url(&input.bucket, &input.key));
+        if let Some(content_type) = input.content_type {
+            req = req.set("Content-Type", &content_type);
+        }
+        if let Some(tagging) = input.tagging {
+            let encoded = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(tagging.iter().map(|tag| (&tag.key, &tag.value)))
+                .finish();
+            req = req.set("x-amz-tagging", &encoded);
+        }
+        self.send(req, &input.body).map(|response| PutObjectOutput {
+            e_tag: response.header("ETag").map(Into::into),
+            version_id: response.header("x-amz-version-id").map(Into::into),
+        })
+    }
+
+    pub fn delete_object(&self, input: DeleteObjectInput) -> Result<DeleteObjectOutput> {
+        let mut req = ureq::delete(&self.object_url(&input.bucket, &input.key));
+        if let Some(version_id) = input.version_id {
+            req = req.query("versionId", &version_id);
+        }
+        self.send(req, &[]).map(|response| DeleteObjectOutput {
+            delete_marker: delete_marker(&response),
+            version_id: response.header("x-amz-version-id").map(Into::into),
+        })
+    }
+
+    pub fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingInput,
+    ) -> Result<GetObjectTaggingOutput> {
+        let mut req = ureq::get(&self.object_url(&input.bucket, &input.key));
+        req = req.query("tagging", "");
+        self.send(req, &[]).and_then(|response| {
+            let body = response.into_reader();
+            let output = serde_xml_rs::from_reader(body)?;
+            Ok(output)
+        })
+    }
+
+    pub fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingInput,
+    ) -> Result<PutObjectTaggingOutput> {
+        let mut req = ureq::put(&self.object_url(&input.bucket, &input.key));
+        req = req.query("tagging", "");
+        let body = xml::to_string(&input.tagging)?;
+        req = req.set("Content-MD5", &content_md5(body.as_bytes()));
+        self.send(req, body.as_bytes())
+            .map(|_| PutObjectTaggingOutput {})
+    }
+
+    pub fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingInput,
+    ) -> Result<DeleteObjectTaggingOutput> {
+        let mut req = ureq::delete(&self.object_url(&input.bucket, &input.key));
+        req = req.query("tagging", "");
+        self.send(req, &[]).map(|_| DeleteObjectTaggingOutput {})
+    }
+
+    fn send(&self, mut req: ureq::Request, body: &[u8]) -> Result<Response> {
         let identity = self.credentials.clone().into();
-        req = sign_request(req, &[], &identity, &self.region, SERVICE_NAME)?;
-        with_retry(|| req.clone().call(), 5).map_err(Into::into)
+        req = sign_request(req, body, &identity, &self.region, SERVICE_NAME)?;
+        with_retry(|| req.clone().send_bytes(body), 5).map_err(Into::into)
+    }
+
+    fn object_url(&self, bucket: &str, key: &str) -> String {
+        format!(
+            "{}/{}",
+            self.url(bucket),
+            utf8_percent_encode(key, KEY_ENCODE_SET)
+        )
+    }
+
+    fn service_url(&self) -> String {
+        format!("https://{}.{}.amazonaws.com", SERVICE_NAME, self.region)
     }
 
     fn url(&self, bucket: &str) -> String {
@@ -128,52 +587,69 @@ impl Api {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct GetObjectInput {
-    pub bucket: String,
-    pub key: String,
+fn content_md5(body: &[u8]) -> String {
+    BASE64.encode(Md5::digest(body))
 }
 
-impl GetObjectInput {
-    pub fn bucket(mut self, bucket: &str) -> Self {
-        self.bucket = bucket.to_string();
-        self
+fn copy_source(bucket: &str, key: &str, version_id: Option<&str>) -> String {
+    let copy_source = format!("/{}/{}", bucket, utf8_percent_encode(key, KEY_ENCODE_SET));
+    match version_id {
+        Some(version_id) => format!(
+            "{}?versionId={}",
+            copy_source,
+            utf8_percent_encode(version_id, KEY_ENCODE_SET)
+        ),
+        None => copy_source,
     }
+}
 
-    pub fn key(mut self, key: &str) -> Self {
-        self.key = key.to_string();
-        self
+fn is_error_document(body: &str) -> bool {
+    let mut root = body.trim_start();
+    if let Some(declaration) = root.strip_prefix("<?xml") {
+        root = declaration
+            .split_once("?>")
+            .map_or("", |(_, rest)| rest.trim_start());
     }
+    root.starts_with("<Error>")
 }
 
-pub struct GetObjectOutput {
-    pub body: Box<dyn Read + Send + Sync + 'static>,
+#[derive(Debug, PartialEq, Eq)]
+enum RestoreProgress {
+    Restored,
+    Ongoing,
+    NotRequested,
 }
 
-impl Debug for GetObjectOutput {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("GetObjectOutput")
-            .field("body", &"[..]")
-            .finish()
+fn restore_progress(output: &HeadObjectOutput) -> RestoreProgress {
+    match (&output.restore, output.storage_class.as_deref()) {
+        (Some(restore), _) if restore.ongoing_request => RestoreProgress::Ongoing,
+        (None, Some("GLACIER" | "DEEP_ARCHIVE")) => RestoreProgress::NotRequested,
+        _ => RestoreProgress::Restored,
     }
 }
 
+fn delete_marker(response: &Response) -> Option<bool> {
+    response
+        .header("x-amz-delete-marker")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct ListObjectsV2Input {
-    pub bucket: String,
+pub struct ListBucketsInput {
     pub continuation_token: Option<String>,
+    pub max_buckets: Option<u32>,
     pub prefix: Option<String>,
 }
 
-impl ListObjectsV2Input {
-    pub fn bucket(mut self, bucket: &str) -> Self {
-        self.bucket = bucket.to_string();
+impl ListBucketsInput {
+    pub fn continuation_token(mut self, continuation_token: &str) -> Self {
+        self.continuation_token = Some(continuation_token.to_string());
         self
     }
 
-    pub fn continuation_token(mut self, continuation_token: &str) -> Self {
-        self.continuation_token = Some(continuation_token.to_string());
+    pub fn max_buckets(mut self, max_buckets: u32) -> Self {
+        self.max_buckets = Some(max_buckets);
         self
     }
 
@@ -185,69 +661,1338 @@ impl ListObjectsV2Input {
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct ListObjectsV2Output {
-    #[serde(rename = "CommonPrefixes")]
-    pub common_prefixes: Option<Vec<CommonPrefix>>,
-    #[serde(rename = "Contents")]
-    pub contents: Option<Vec<Object>>,
+pub struct ListBucketsOutput {
+    #[serde(rename = "Buckets")]
+    pub buckets: Option<Buckets>,
     #[serde(rename = "ContinuationToken")]
     pub continuation_token: Option<String>,
-    #[serde(rename = "Delimiter")]
-    pub delimiter: Option<String>,
-    #[serde(rename = "EncodingType")]
-    pub encoding_type: Option<String>,
-    #[serde(rename = "IsTruncated")]
-    pub is_truncated: Option<bool>,
-    #[serde(rename = "KeyCount")]
-    pub key_count: Option<u32>,
-    #[serde(rename = "MaxKeys")]
-    pub max_keys: Option<u32>,
-    #[serde(rename = "Name")]
-    pub name: Option<String>,
-    #[serde(rename = "NextContinuationToken")]
-    pub next_continuation_token: Option<String>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Owner>,
     #[serde(rename = "Prefix")]
     pub prefix: Option<String>,
-    #[serde(rename = "StartAfter")]
-    pub start_after: Option<String>,
 }
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct CommonPrefix {
-    #[serde(rename = "Prefix")]
-    prefix: String,
+pub struct Buckets {
+    #[serde(rename = "Bucket")]
+    pub items: Option<Vec<Bucket>>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Object {
-    #[serde(rename = "ChecksumAlgorithm")]
-    pub checksum_algorithm: Option<Vec<String>>,
-    #[serde(rename = "ETag")]
-    pub e_tag: Option<Vec<String>>,
-    #[serde(rename = "Key")]
-    pub key: Option<String>,
-    #[serde(rename = "LastModified")]
-    pub last_modified: Option<DateTime<Utc>>,
-    #[serde(rename = "Owner")]
-    pub owner: Option<String>,
-    #[serde(rename = "RestoreStatus")]
-    pub restore_status: Option<String>,
-    #[serde(rename = "Size")]
-    pub size: Option<i64>,
-    #[serde(rename = "StorageClass")]
-    pub storage_class: Option<String>,
+pub struct Bucket {
+    #[serde(rename = "BucketRegion")]
+    pub bucket_region: Option<String>,
+    #[serde(rename = "CreationDate")]
+    pub creation_date: Option<DateTime<Utc>>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct ErrorBody {
-    #[serde(rename = "Code")]
-    pub code: String,
-    #[serde(rename = "Message")]
-    pub message: String,
-    #[serde(rename = "Resource")]
-    pub resource: Option<String>,
-    #[serde(rename = "RequestId")]
-    pub request_id: String,
+pub struct CreateBucketInput {
+    pub bucket: String,
+    pub location_constraint: Option<String>,
+}
+
+impl CreateBucketInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn location_constraint(mut self, location_constraint: &str) -> Self {
+        self.location_constraint = Some(location_constraint.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "CreateBucketConfiguration")]
+pub struct CreateBucketConfiguration {
+    #[serde(rename = "LocationConstraint")]
+    pub location_constraint: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBucketOutput {
+    pub location: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteBucketInput {
+    pub bucket: String,
+}
+
+impl DeleteBucketInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteBucketOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HeadBucketInput {
+    pub bucket: String,
+}
+
+impl HeadBucketInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HeadBucketOutput {
+    pub access_point_alias: Option<bool>,
+    pub bucket_region: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketVersioningInput {
+    pub bucket: String,
+}
+
+impl GetBucketVersioningInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketVersioningOutput {
+    #[serde(rename = "MfaDelete")]
+    pub mfa_delete: Option<MfaDeleteStatus>,
+    #[serde(rename = "Status")]
+    pub status: Option<BucketVersioningStatus>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketVersioningInput {
+    pub bucket: String,
+    pub versioning_configuration: VersioningConfiguration,
+}
+
+impl PutBucketVersioningInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn versioning_configuration(
+        mut self,
+        versioning_configuration: VersioningConfiguration,
+    ) -> Self {
+        self.versioning_configuration = versioning_configuration;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketVersioningOutput {}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "VersioningConfiguration")]
+pub struct VersioningConfiguration {
+    #[serde(rename = "MfaDelete")]
+    pub mfa_delete: Option<MfaDeleteStatus>,
+    #[serde(rename = "Status")]
+    pub status: Option<BucketVersioningStatus>,
+}
+
+impl VersioningConfiguration {
+    pub fn mfa_delete(mut self, mfa_delete: MfaDeleteStatus) -> Self {
+        self.mfa_delete = Some(mfa_delete);
+        self
+    }
+
+    pub fn status(mut self, status: BucketVersioningStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum BucketVersioningStatus {
+    Enabled,
+    Suspended,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum MfaDeleteStatus {
+    Enabled,
+    Disabled,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketLifecycleConfigurationInput {
+    pub bucket: String,
+}
+
+impl GetBucketLifecycleConfigurationInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketLifecycleConfigurationOutput {
+    #[serde(rename = "Rule")]
+    pub rules: Option<Vec<LifecycleRule>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketLifecycleConfigurationInput {
+    pub bucket: String,
+    pub lifecycle_configuration: BucketLifecycleConfiguration,
+}
+
+impl PutBucketLifecycleConfigurationInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn lifecycle_configuration(
+        mut self,
+        lifecycle_configuration: BucketLifecycleConfiguration,
+    ) -> Self {
+        self.lifecycle_configuration = lifecycle_configuration;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketLifecycleConfigurationOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "LifecycleConfiguration")]
+pub struct BucketLifecycleConfiguration {
+    #[serde(rename = "Rule")]
+    pub rules: Vec<LifecycleRule>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LifecycleRule {
+    #[serde(rename = "AbortIncompleteMultipartUpload")]
+    pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
+    #[serde(rename = "Expiration")]
+    pub expiration: Option<LifecycleExpiration>,
+    #[serde(rename = "Filter")]
+    pub filter: Option<LifecycleRuleFilter>,
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    #[serde(rename = "NoncurrentVersionExpiration")]
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+    #[serde(rename = "NoncurrentVersionTransition")]
+    pub noncurrent_version_transitions: Option<Vec<NoncurrentVersionTransition>>,
+    #[serde(rename = "Status")]
+    pub status: ExpirationStatus,
+    #[serde(rename = "Transition")]
+    pub transitions: Option<Vec<Transition>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum ExpirationStatus {
+    Enabled,
+    Disabled,
+
+    #[serde(other)]
+    #[default]
+    Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AbortIncompleteMultipartUpload {
+    #[serde(rename = "DaysAfterInitiation")]
+    pub days_after_initiation: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LifecycleExpiration {
+    #[serde(rename = "Date")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(rename = "Days")]
+    pub days: Option<u32>,
+    #[serde(rename = "ExpiredObjectDeleteMarker")]
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LifecycleRuleFilter {
+    #[serde(rename = "And")]
+    pub and: Option<LifecycleRuleAndOperator>,
+    #[serde(rename = "ObjectSizeGreaterThan")]
+    pub object_size_greater_than: Option<i64>,
+    #[serde(rename = "ObjectSizeLessThan")]
+    pub object_size_less_than: Option<i64>,
+    #[serde(rename = "Prefix")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag")]
+    pub tag: Option<Tag>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LifecycleRuleAndOperator {
+    #[serde(rename = "ObjectSizeGreaterThan")]
+    pub object_size_greater_than: Option<i64>,
+    #[serde(rename = "ObjectSizeLessThan")]
+    pub object_size_less_than: Option<i64>,
+    #[serde(rename = "Prefix")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NoncurrentVersionExpiration {
+    #[serde(rename = "NewerNoncurrentVersions")]
+    pub newer_noncurrent_versions: Option<u32>,
+    #[serde(rename = "NoncurrentDays")]
+    pub noncurrent_days: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NoncurrentVersionTransition {
+    #[serde(rename = "NewerNoncurrentVersions")]
+    pub newer_noncurrent_versions: Option<u32>,
+    #[serde(rename = "NoncurrentDays")]
+    pub noncurrent_days: Option<u32>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Transition {
+    #[serde(rename = "Date")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(rename = "Days")]
+    pub days: Option<u32>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketPolicyInput {
+    pub bucket: String,
+}
+
+impl GetBucketPolicyInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketPolicyOutput {
+    pub policy: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketPolicyInput {
+    pub bucket: String,
+    pub policy: String,
+}
+
+impl PutBucketPolicyInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn policy(mut self, policy: &str) -> Self {
+        self.policy = policy.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketPolicyOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetPublicAccessBlockInput {
+    pub bucket: String,
+}
+
+impl GetPublicAccessBlockInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetPublicAccessBlockOutput {
+    pub public_access_block_configuration: PublicAccessBlockConfiguration,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutPublicAccessBlockInput {
+    pub bucket: String,
+    pub public_access_block_configuration: PublicAccessBlockConfiguration,
+}
+
+impl PutPublicAccessBlockInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn public_access_block_configuration(
+        mut self,
+        public_access_block_configuration: PublicAccessBlockConfiguration,
+    ) -> Self {
+        self.public_access_block_configuration = public_access_block_configuration;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutPublicAccessBlockOutput {}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "PublicAccessBlockConfiguration")]
+pub struct PublicAccessBlockConfiguration {
+    #[serde(rename = "BlockPublicAcls")]
+    pub block_public_acls: Option<bool>,
+    #[serde(rename = "BlockPublicPolicy")]
+    pub block_public_policy: Option<bool>,
+    #[serde(rename = "IgnorePublicAcls")]
+    pub ignore_public_acls: Option<bool>,
+    #[serde(rename = "RestrictPublicBuckets")]
+    pub restrict_public_buckets: Option<bool>,
+}
+
+impl PublicAccessBlockConfiguration {
+    pub fn block_public_acls(mut self, block_public_acls: bool) -> Self {
+        self.block_public_acls = Some(block_public_acls);
+        self
+    }
+
+    pub fn block_public_policy(mut self, block_public_policy: bool) -> Self {
+        self.block_public_policy = Some(block_public_policy);
+        self
+    }
+
+    pub fn ignore_public_acls(mut self, ignore_public_acls: bool) -> Self {
+        self.ignore_public_acls = Some(ignore_public_acls);
+        self
+    }
+
+    pub fn restrict_public_buckets(mut self, restrict_public_buckets: bool) -> Self {
+        self.restrict_public_buckets = Some(restrict_public_buckets);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketEncryptionInput {
+    pub bucket: String,
+}
+
+impl GetBucketEncryptionInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetBucketEncryptionOutput {
+    pub server_side_encryption_configuration: ServerSideEncryptionConfiguration,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketEncryptionInput {
+    pub bucket: String,
+    pub server_side_encryption_configuration: ServerSideEncryptionConfiguration,
+}
+
+impl PutBucketEncryptionInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn server_side_encryption_configuration(
+        mut self,
+        server_side_encryption_configuration: ServerSideEncryptionConfiguration,
+    ) -> Self {
+        self.server_side_encryption_configuration = server_side_encryption_configuration;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutBucketEncryptionOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "ServerSideEncryptionConfiguration")]
+pub struct ServerSideEncryptionConfiguration {
+    #[serde(rename = "Rule")]
+    pub rules: Vec<ServerSideEncryptionRule>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ServerSideEncryptionRule {
+    #[serde(rename = "ApplyServerSideEncryptionByDefault")]
+    pub apply_server_side_encryption_by_default: Option<ServerSideEncryptionByDefault>,
+    #[serde(rename = "BucketKeyEnabled")]
+    pub bucket_key_enabled: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ServerSideEncryptionByDefault {
+    #[serde(rename = "KMSMasterKeyID")]
+    pub kms_master_key_id: Option<String>,
+    #[serde(rename = "SSEAlgorithm")]
+    pub sse_algorithm: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetObjectInput {
+    pub bucket: String,
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+impl GetObjectInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn version_id(mut self, version_id: &str) -> Self {
+        self.version_id = Some(version_id.to_string());
+        self
+    }
+}
+
+pub struct GetObjectOutput {
+    pub body: Box<dyn Read + Send + Sync + 'static>,
+    pub delete_marker: Option<bool>,
+    pub restore: Option<ObjectRestore>,
+    pub storage_class: Option<String>,
+    pub version_id: Option<String>,
+}
+
+impl Debug for GetObjectOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GetObjectOutput")
+            .field("body", &"[..]")
+            .field("delete_marker", &self.delete_marker)
+            .field("restore", &self.restore)
+            .field("storage_class", &self.storage_class)
+            .field("version_id", &self.version_id)
+            .finish()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HeadObjectInput {
+    pub bucket: String,
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+impl HeadObjectInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn version_id(mut self, version_id: &str) -> Self {
+        self.version_id = Some(version_id.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HeadObjectOutput {
+    pub content_length: Option<u64>,
+    pub content_type: Option<String>,
+    pub delete_marker: Option<bool>,
+    pub e_tag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub restore: Option<ObjectRestore>,
+    pub storage_class: Option<String>,
+    pub version_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ObjectRestore {
+    pub expiry_date: Option<DateTime<Utc>>,
+    pub ongoing_request: bool,
+}
+
+impl ObjectRestore {
+    fn parse(header: &str) -> Option<Self> {
+        let value = |name: &str| {
+            let start = header.find(&format!("{}=\"", name))? + name.len() + 2;
+            let end = header[start..].find('"')? + start;
+            Some(&header[start..end])
+        };
+        Some(Self {
+            expiry_date: value("expiry-date")
+                .and_then(|v| DateTime::parse_from_rfc2822(v).ok())
+                .map(Into::into),
+            ongoing_request: value("ongoing-request")?.eq_ignore_ascii_case("true"),
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RestoreObjectInput {
+    pub bucket: String,
+    pub key: String,
+    pub restore_request: RestoreRequest,
+    pub version_id: Option<String>,
+}
+
+impl RestoreObjectInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn days(mut self, days: u32) -> Self {
+        self.restore_request.days = Some(days);
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn tier(mut self, tier: Tier) -> Self {
+        self.restore_request.glacier_job_parameters = Some(GlacierJobParameters { tier });
+        self
+    }
+
+    pub fn version_id(mut self, version_id: &str) -> Self {
+        self.version_id = Some(version_id.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "RestoreRequest")]
+pub struct RestoreRequest {
+    #[serde(rename = "Days")]
+    pub days: Option<u32>,
+    #[serde(rename = "GlacierJobParameters")]
+    pub glacier_job_parameters: Option<GlacierJobParameters>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GlacierJobParameters {
+    #[serde(rename = "Tier")]
+    pub tier: Tier,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum Tier {
+    Bulk,
+    Expedited,
+    #[default]
+    Standard,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RestoreObjectOutput {
+    pub already_restored: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopyObjectInput {
+    pub bucket: String,
+    pub key: String,
+    pub source_bucket: String,
+    pub source_key: String,
+    pub source_version_id: Option<String>,
+}
+
+impl CopyObjectInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn source_bucket(mut self, source_bucket: &str) -> Self {
+        self.source_bucket = source_bucket.to_string();
+        self
+    }
+
+    pub fn source_key(mut self, source_key: &str) -> Self {
+        self.source_key = source_key.to_string();
+        self
+    }
+
+    pub fn source_version_id(mut self, source_version_id: &str) -> Self {
+        self.source_version_id = Some(source_version_id.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopyObjectOutput {
+    pub copy_object_result: CopyObjectResult,
+    pub copy_source_version_id: Option<String>,
+    pub version_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub e_tag: Option<String>,
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutObjectInput {
+    pub body: Vec<u8>,
+    pub bucket: String,
+    pub content_type: Option<String>,
+    pub key: String,
+    pub tagging: Option<Vec<Tag>>,
+}
+
+impl PutObjectInput {
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn tagging(mut self, tagging: Vec<Tag>) -> Self {
+        self.tagging = Some(tagging);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutObjectOutput {
+    pub e_tag: Option<String>,
+    pub version_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteObjectInput {
+    pub bucket: String,
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+impl DeleteObjectInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn version_id(mut self, version_id: &str) -> Self {
+        self.version_id = Some(version_id.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteObjectOutput {
+    pub delete_marker: Option<bool>,
+    pub version_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetObjectTaggingInput {
+    pub bucket: String,
+    pub key: String,
+}
+
+impl GetObjectTaggingInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GetObjectTaggingOutput {
+    #[serde(rename = "TagSet")]
+    pub tag_set: TagSet,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutObjectTaggingInput {
+    pub bucket: String,
+    pub key: String,
+    pub tagging: Tagging,
+}
+
+impl PutObjectTaggingInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn tagging(mut self, tagging: Tagging) -> Self {
+        self.tagging = tagging;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PutObjectTaggingOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteObjectTaggingInput {
+    pub bucket: String,
+    pub key: String,
+}
+
+impl DeleteObjectTaggingInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteObjectTaggingOutput {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "Tagging")]
+pub struct Tagging {
+    #[serde(rename = "TagSet")]
+    pub tag_set: TagSet,
+}
+
+impl From<Vec<Tag>> for Tagging {
+    fn from(tags: Vec<Tag>) -> Self {
+        Self {
+            tag_set: TagSet { tags: Some(tags) },
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TagSet {
+    #[serde(rename = "Tag")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+impl Tag {
+    pub fn new(key: &str, value: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListObjectsV2Input {
+    pub bucket: String,
+    pub continuation_token: Option<String>,
+    pub prefix: Option<String>,
+}
+
+impl ListObjectsV2Input {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn continuation_token(mut self, continuation_token: &str) -> Self {
+        self.continuation_token = Some(continuation_token.to_string());
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListObjectsV2Output {
+    #[serde(rename = "CommonPrefixes")]
+    pub common_prefixes: Option<Vec<CommonPrefix>>,
+    #[serde(rename = "Contents")]
+    pub contents: Option<Vec<Object>>,
+    #[serde(rename = "ContinuationToken")]
+    pub continuation_token: Option<String>,
+    #[serde(rename = "Delimiter")]
+    pub delimiter: Option<String>,
+    #[serde(rename = "EncodingType")]
+    pub encoding_type: Option<String>,
+    #[serde(rename = "IsTruncated")]
+    pub is_truncated: Option<bool>,
+    #[serde(rename = "KeyCount")]
+    pub key_count: Option<u32>,
+    #[serde(rename = "MaxKeys")]
+    pub max_keys: Option<u32>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "NextContinuationToken")]
+    pub next_continuation_token: Option<String>,
+    #[serde(rename = "Prefix")]
+    pub prefix: Option<String>,
+    #[serde(rename = "StartAfter")]
+    pub start_after: Option<String>,
+}
+
+pub struct ListObjectsV2Paginator<'a> {
+    api: &'a Api,
+    input: ListObjectsV2Input,
+    done: bool,
+}
+
+impl Iterator for ListObjectsV2Paginator<'_> {
+    type Item = Result<ListObjectsV2Output>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.list_objects_v2(self.input.clone());
+        match &result {
+            Ok(output) => match (output.is_truncated, &output.next_continuation_token) {
+                (Some(true), Some(token)) => self.input.continuation_token = Some(token.clone()),
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListObjectVersionsInput {
+    pub bucket: String,
+    pub delimiter: Option<String>,
+    pub key_marker: Option<String>,
+    pub max_keys: Option<u32>,
+    pub prefix: Option<String>,
+    pub version_id_marker: Option<String>,
+}
+
+impl ListObjectVersionsInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: &str) -> Self {
+        self.delimiter = Some(delimiter.to_string());
+        self
+    }
+
+    pub fn key_marker(mut self, key_marker: &str) -> Self {
+        self.key_marker = Some(key_marker.to_string());
+        self
+    }
+
+    pub fn max_keys(mut self, max_keys: u32) -> Self {
+        self.max_keys = Some(max_keys);
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn version_id_marker(mut self, version_id_marker: &str) -> Self {
+        self.version_id_marker = Some(version_id_marker.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(from = "ListVersionsResult")]
+pub struct ListObjectVersionsOutput {
+    #[serde(rename = "CommonPrefixes")]
+    pub common_prefixes: Option<Vec<CommonPrefix>>,
+    #[serde(rename = "DeleteMarker")]
+    pub delete_markers: Option<Vec<DeleteMarkerEntry>>,
+    #[serde(rename = "Delimiter")]
+    pub delimiter: Option<String>,
+    #[serde(rename = "EncodingType")]
+    pub encoding_type: Option<String>,
+    #[serde(rename = "IsTruncated")]
+    pub is_truncated: Option<bool>,
+    #[serde(rename = "KeyMarker")]
+    pub key_marker: Option<String>,
+    #[serde(rename = "MaxKeys")]
+    pub max_keys: Option<u32>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    #[serde(rename = "NextKeyMarker")]
+    pub next_key_marker: Option<String>,
+    #[serde(rename = "NextVersionIdMarker")]
+    pub next_version_id_marker: Option<String>,
+    #[serde(rename = "Prefix")]
+    pub prefix: Option<String>,
+    #[serde(rename = "VersionIdMarker")]
+    pub version_id_marker: Option<String>,
+    #[serde(rename = "Version")]
+    pub versions: Option<Vec<ObjectVersion>>,
+}
+
+// Versions and delete markers are interleaved in the response, so they are
+// collected in document order before being split into their own lists.
+#[derive(Deserialize)]
+struct ListVersionsResult {
+    #[serde(default, rename = "$value")]
+    fields: Vec<ListVersionsResultField>,
+}
+
+#[derive(Deserialize)]
+enum ListVersionsResultField {
+    CommonPrefixes(CommonPrefix),
+    DeleteMarker(DeleteMarkerEntry),
+    Delimiter(String),
+    EncodingType(String),
+    IsTruncated(bool),
+    KeyMarker(String),
+    MaxKeys(u32),
+    Name(String),
+    NextKeyMarker(String),
+    NextVersionIdMarker(String),
+    Prefix(String),
+    Version(ObjectVersion),
+    VersionIdMarker(String),
+}
+
+impl From<ListVersionsResult> for ListObjectVersionsOutput {
+    fn from(result: ListVersionsResult) -> Self {
+        let mut output = Self::default();
+        for field in result.fields {
+            match field {
+                ListVersionsResultField::CommonPrefixes(v) => {
+                    output.common_prefixes.get_or_insert_with(Vec::new).push(v)
+                }
+                ListVersionsResultField::DeleteMarker(v) => {
+                    output.delete_markers.get_or_insert_with(Vec::new).push(v)
+                }
+                ListVersionsResultField::Delimiter(v) => output.delimiter = Some(v),
+                ListVersionsResultField::EncodingType(v) => output.encoding_type = Some(v),
+                ListVersionsResultField::IsTruncated(v) => output.is_truncated = Some(v),
+                ListVersionsResultField::KeyMarker(v) => output.key_marker = Some(v),
+                ListVersionsResultField::MaxKeys(v) => output.max_keys = Some(v),
+                ListVersionsResultField::Name(v) => output.name = Some(v),
+                ListVersionsResultField::NextKeyMarker(v) => output.next_key_marker = Some(v),
+                ListVersionsResultField::NextVersionIdMarker(v) => {
+                    output.next_version_id_marker = Some(v)
+                }
+                ListVersionsResultField::Prefix(v) => output.prefix = Some(v),
+                ListVersionsResultField::Version(v) => {
+                    output.versions.get_or_insert_with(Vec::new).push(v)
+                }
+                ListVersionsResultField::VersionIdMarker(v) => output.version_id_marker = Some(v),
+            }
+        }
+        output
+    }
+}
+
+pub struct ListObjectVersionsPaginator<'a> {
+    api: &'a Api,
+    input: ListObjectVersionsInput,
+    done: bool,
+}
+
+impl Iterator for ListObjectVersionsPaginator<'_> {
+    type Item = Result<ListObjectVersionsOutput>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.api.list_object_versions(self.input.clone());
+        match &result {
+            Ok(output) => match (output.is_truncated, &output.next_key_marker) {
+                (Some(true), Some(marker)) if !marker.is_empty() => {
+                    self.input.key_marker = Some(marker.clone());
+                    self.input.version_id_marker = output.next_version_id_marker.clone();
+                }
+                _ => self.done = true,
+            },
+            Err(_) => self.done = true,
+        }
+        Some(result)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ObjectVersion {
+    #[serde(rename = "ETag")]
+    pub e_tag: Option<String>,
+    #[serde(rename = "IsLatest")]
+    pub is_latest: Option<bool>,
+    #[serde(rename = "Key")]
+    pub key: Option<String>,
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Owner>,
+    #[serde(rename = "Size")]
+    pub size: Option<i64>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+    #[serde(rename = "VersionId")]
+    pub version_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeleteMarkerEntry {
+    #[serde(rename = "IsLatest")]
+    pub is_latest: Option<bool>,
+    #[serde(rename = "Key")]
+    pub key: Option<String>,
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Owner>,
+    #[serde(rename = "VersionId")]
+    pub version_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Owner {
+    #[serde(rename = "DisplayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CommonPrefix {
+    #[serde(rename = "Prefix")]
+    prefix: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Object {
+    #[serde(rename = "ChecksumAlgorithm")]
+    pub checksum_algorithm: Option<Vec<String>>,
+    #[serde(rename = "ETag")]
+    pub e_tag: Option<Vec<String>>,
+    #[serde(rename = "Key")]
+    pub key: Option<String>,
+    #[serde(rename = "LastModified")]
+    pub last_modified: Option<DateTime<Utc>>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<String>,
+    #[serde(rename = "RestoreStatus")]
+    pub restore_status: Option<String>,
+    #[serde(rename = "Size")]
+    pub size: Option<i64>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ErrorBody {
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "Message")]
+    pub message: String,
+    #[serde(rename = "Resource")]
+    pub resource: Option<String>,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_progress_states() {
+        let archived = |restore: Option<&str>| HeadObjectOutput {
+            restore: restore.and_then(ObjectRestore::parse),
+            storage_class: Some("GLACIER".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            restore_progress(&archived(Some(r#"ongoing-request="true""#))),
+            RestoreProgress::Ongoing
+        );
+        assert_eq!(
+            restore_progress(&archived(Some(
+                r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#
+            ))),
+            RestoreProgress::Restored
+        );
+        assert_eq!(
+            restore_progress(&archived(None)),
+            RestoreProgress::NotRequested
+        );
+        assert_eq!(
+            restore_progress(&HeadObjectOutput::default()),
+            RestoreProgress::Restored
+        );
+    }
+
+    #[test]
+    fn copy_source_is_encoded() {
+        assert_eq!(
+            copy_source("bucket", "dir/a b+c.txt", None),
+            "/bucket/dir/a%20b%2Bc.txt"
+        );
+        assert_eq!(
+            copy_source("bucket", "key", Some("3/L4kqtJl+cPv=e&x")),
+            "/bucket/key?versionId=3/L4kqtJl%2BcPv%3De%26x"
+        );
+    }
+
+    #[test]
+    fn copy_object_error_document() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error><Code>InternalError</Code><Message>We encountered an internal error.</Message><RequestId>ABC</RequestId></Error>"#;
+        assert!(is_error_document(body));
+        let error: ErrorBody = serde_xml_rs::from_str(body).unwrap();
+        assert_eq!(error.code, "InternalError");
+
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CopyObjectResult><ETag>"abc"</ETag><LastModified>2024-01-01T00:00:00.000Z</LastModified></CopyObjectResult>"#;
+        assert!(!is_error_document(body));
+    }
+
+    #[test]
+    fn empty_error_body_uses_status() {
+        let response: Response = "HTTP/1.1 404 Not Found\r\nx-amz-request-id: ABC\r\n\r\n"
+            .parse()
+            .unwrap();
+        let error = Error::from(request::Error::Api(404, Box::new(response)));
+        assert!(error.is_not_found());
+        match error {
+            Error::S3(eb) => {
+                assert_eq!(eb.code, "NotFound");
+                assert_eq!(eb.request_id, "ABC");
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let response: Response = "HTTP/1.1 403 Forbidden\r\n\r\n".parse().unwrap();
+        let error = Error::from(request::Error::Api(403, Box::new(response)));
+        assert!(!error.is_not_found());
+        assert!(matches!(error, Error::S3(eb) if eb.code == "Forbidden"));
+    }
 }
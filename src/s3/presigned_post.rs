@@ -0,0 +1,162 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime},
+};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+use super::{Api, SERVICE_NAME};
+use crate::request::sign_string;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Condition {
+    ContentLengthRange(u64, u64),
+    Equals(String, String),
+    StartsWith(String, String),
+}
+
+impl Condition {
+    fn to_json(&self) -> Value {
+        match self {
+            Self::ContentLengthRange(min, max) => json!(["content-length-range", min, max]),
+            Self::Equals(field, value) => json!({ field: value }),
+            Self::StartsWith(field, prefix) => {
+                json!(["starts-with", format!("${}", field), prefix])
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PresignedPostInput {
+    pub bucket: String,
+    pub conditions: Vec<Condition>,
+    pub expires_in: Duration,
+    pub fields: BTreeMap<String, String>,
+    pub key: String,
+}
+
+impl Default for PresignedPostInput {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            conditions: Vec::new(),
+            expires_in: Duration::from_secs(3600),
+            fields: BTreeMap::new(),
+            key: String::new(),
+        }
+    }
+}
+
+impl PresignedPostInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn condition(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn content_length_range(self, min: u64, max: u64) -> Self {
+        self.condition(Condition::ContentLengthRange(min, max))
+    }
+
+    pub fn content_type(self, content_type: &str) -> Self {
+        self.field("Content-Type", content_type)
+    }
+
+    pub fn content_type_starts_with(self, prefix: &str) -> Self {
+        self.condition(Condition::StartsWith("Content-Type".into(), prefix.into()))
+    }
+
+    pub fn expires_in(mut self, expires_in: Duration) -> Self {
+        self.expires_in = expires_in;
+        self
+    }
+
+    pub fn field(mut self, name: &str, value: &str) -> Self {
+        self.fields.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn key_starts_with(mut self, prefix: &str) -> Self {
+        self.key = format!("{}${{filename}}", prefix);
+        self.condition(Condition::StartsWith("key".into(), prefix.into()))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PresignedPost {
+    pub fields: BTreeMap<String, String>,
+    pub url: String,
+}
+
+impl Api {
+    pub fn presigned_post(&self, input: PresignedPostInput) -> PresignedPost {
+        let now = SystemTime::now();
+        let timestamp: DateTime<Utc> = now.into();
+        let expiration = timestamp + input.expires_in;
+        let date = timestamp.format("%Y%m%d").to_string();
+        let credential = format!(
+            "{}/{}/{}/{}/aws4_request",
+            self.credentials.access_key_id(),
+            date,
+            self.region,
+            SERVICE_NAME
+        );
+
+        let mut fields = input.fields;
+        fields.insert("key".into(), input.key);
+        fields.insert("x-amz-algorithm".into(), ALGORITHM.into());
+        fields.insert("x-amz-credential".into(), credential);
+        fields.insert(
+            "x-amz-date".into(),
+            timestamp.format("%Y%m%dT%H%M%SZ").to_string(),
+        );
+        if let Some(session_token) = self.credentials.session_token() {
+            fields.insert("x-amz-security-token".into(), session_token.into());
+        }
+
+        let mut conditions = vec![json!({ "bucket": input.bucket })];
+        for (name, value) in &fields {
+            let starts_with = input.conditions.iter().any(
+                |c| matches!(c, Condition::StartsWith(field, _) if field.eq_ignore_ascii_case(name)),
+            );
+            if !starts_with {
+                conditions.push(Condition::Equals(name.clone(), value.clone()).to_json());
+            }
+        }
+        conditions.extend(input.conditions.iter().map(Condition::to_json));
+
+        let policy = json!({
+            "expiration": expiration.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "conditions": conditions,
+        });
+        let encoded_policy = BASE64.encode(policy.to_string());
+        let signature = sign_string(
+            &encoded_policy,
+            self.credentials.secret_access_key(),
+            now,
+            &self.region,
+            SERVICE_NAME,
+        );
+        fields.insert("policy".into(), encoded_policy);
+        fields.insert("x-amz-signature".into(), signature);
+
+        PresignedPost {
+            fields,
+            url: self.url(&input.bucket),
+        }
+    }
+}
@@ -0,0 +1,556 @@
+use std::{
+    fmt::{Debug, Formatter},
+    io::{self, Read},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::{xml, Api, Error, ErrorBody, Result};
+
+// A prelude and message checksum with no headers or payload.
+const MIN_MESSAGE_LENGTH: usize = 16;
+const MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SelectObjectContentInput {
+    pub bucket: String,
+    pub key: String,
+    pub request: SelectObjectContentRequest,
+}
+
+impl SelectObjectContentInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn expression(mut self, expression: &str) -> Self {
+        self.request.expression = expression.to_string();
+        self
+    }
+
+    pub fn input_serialization(mut self, input_serialization: InputSerialization) -> Self {
+        self.request.input_serialization = input_serialization;
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.key = key.to_string();
+        self
+    }
+
+    pub fn output_serialization(mut self, output_serialization: OutputSerialization) -> Self {
+        self.request.output_serialization = output_serialization;
+        self
+    }
+
+    pub fn request_progress(mut self, enabled: bool) -> Self {
+        self.request.request_progress = Some(RequestProgress {
+            enabled: Some(enabled),
+        });
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename = "SelectObjectContentRequest")]
+pub struct SelectObjectContentRequest {
+    #[serde(rename = "Expression")]
+    pub expression: String,
+    #[serde(rename = "ExpressionType")]
+    pub expression_type: ExpressionType,
+    #[serde(rename = "InputSerialization")]
+    pub input_serialization: InputSerialization,
+    #[serde(rename = "OutputSerialization")]
+    pub output_serialization: OutputSerialization,
+    #[serde(rename = "RequestProgress")]
+    pub request_progress: Option<RequestProgress>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum ExpressionType {
+    #[default]
+    #[serde(rename = "SQL")]
+    Sql,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RequestProgress {
+    #[serde(rename = "Enabled")]
+    pub enabled: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InputSerialization {
+    #[serde(rename = "CompressionType")]
+    pub compression_type: Option<CompressionType>,
+    #[serde(rename = "CSV")]
+    pub csv: Option<CsvInput>,
+    #[serde(rename = "JSON")]
+    pub json: Option<JsonInput>,
+    #[serde(rename = "Parquet")]
+    pub parquet: Option<ParquetInput>,
+}
+
+impl InputSerialization {
+    pub fn compression_type(mut self, compression_type: CompressionType) -> Self {
+        self.compression_type = Some(compression_type);
+        self
+    }
+
+    pub fn csv(mut self, csv: CsvInput) -> Self {
+        self.csv = Some(csv);
+        self
+    }
+
+    pub fn json(mut self, json: JsonInput) -> Self {
+        self.json = Some(json);
+        self
+    }
+
+    pub fn parquet(mut self) -> Self {
+        self.parquet = Some(ParquetInput {});
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum CompressionType {
+    #[default]
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "GZIP")]
+    Gzip,
+    #[serde(rename = "BZIP2")]
+    Bzip2,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CsvInput {
+    #[serde(rename = "AllowQuotedRecordDelimiter")]
+    pub allow_quoted_record_delimiter: Option<bool>,
+    #[serde(rename = "Comments")]
+    pub comments: Option<String>,
+    #[serde(rename = "FieldDelimiter")]
+    pub field_delimiter: Option<String>,
+    #[serde(rename = "FileHeaderInfo")]
+    pub file_header_info: Option<FileHeaderInfo>,
+    #[serde(rename = "QuoteCharacter")]
+    pub quote_character: Option<String>,
+    #[serde(rename = "QuoteEscapeCharacter")]
+    pub quote_escape_character: Option<String>,
+    #[serde(rename = "RecordDelimiter")]
+    pub record_delimiter: Option<String>,
+}
+
+impl CsvInput {
+    pub fn field_delimiter(mut self, field_delimiter: &str) -> Self {
+        self.field_delimiter = Some(field_delimiter.to_string());
+        self
+    }
+
+    pub fn file_header_info(mut self, file_header_info: FileHeaderInfo) -> Self {
+        self.file_header_info = Some(file_header_info);
+        self
+    }
+
+    pub fn record_delimiter(mut self, record_delimiter: &str) -> Self {
+        self.record_delimiter = Some(record_delimiter.to_string());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum FileHeaderInfo {
+    #[default]
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "IGNORE")]
+    Ignore,
+    #[serde(rename = "USE")]
+    Use,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct JsonInput {
+    #[serde(rename = "Type")]
+    pub r#type: Option<JsonType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum JsonType {
+    #[default]
+    #[serde(rename = "DOCUMENT")]
+    Document,
+    #[serde(rename = "LINES")]
+    Lines,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ParquetInput {}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OutputSerialization {
+    #[serde(rename = "CSV")]
+    pub csv: Option<CsvOutput>,
+    #[serde(rename = "JSON")]
+    pub json: Option<JsonOutput>,
+}
+
+impl OutputSerialization {
+    pub fn csv(mut self, csv: CsvOutput) -> Self {
+        self.csv = Some(csv);
+        self
+    }
+
+    pub fn json(mut self, json: JsonOutput) -> Self {
+        self.json = Some(json);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CsvOutput {
+    #[serde(rename = "FieldDelimiter")]
+    pub field_delimiter: Option<String>,
+    #[serde(rename = "QuoteCharacter")]
+    pub quote_character: Option<String>,
+    #[serde(rename = "QuoteEscapeCharacter")]
+    pub quote_escape_character: Option<String>,
+    #[serde(rename = "QuoteFields")]
+    pub quote_fields: Option<QuoteFields>,
+    #[serde(rename = "RecordDelimiter")]
+    pub record_delimiter: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub enum QuoteFields {
+    #[default]
+    #[serde(rename = "ASNEEDED")]
+    AsNeeded,
+    #[serde(rename = "ALWAYS")]
+    Always,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct JsonOutput {
+    #[serde(rename = "RecordDelimiter")]
+    pub record_delimiter: Option<String>,
+}
+
+pub struct SelectObjectContentOutput {
+    pub events: SelectEventStream,
+}
+
+impl Debug for SelectObjectContentOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectObjectContentOutput")
+            .field("events", &"[..]")
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SelectEvent {
+    Cont,
+    End,
+    Progress(Progress),
+    Records(Vec<u8>),
+    Stats(Stats),
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Progress {
+    #[serde(rename = "BytesProcessed")]
+    pub bytes_processed: Option<i64>,
+    #[serde(rename = "BytesReturned")]
+    pub bytes_returned: Option<i64>,
+    #[serde(rename = "BytesScanned")]
+    pub bytes_scanned: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Stats {
+    #[serde(rename = "BytesProcessed")]
+    pub bytes_processed: Option<i64>,
+    #[serde(rename = "BytesReturned")]
+    pub bytes_returned: Option<i64>,
+    #[serde(rename = "BytesScanned")]
+    pub bytes_scanned: Option<i64>,
+}
+
+pub struct SelectEventStream {
+    done: bool,
+    reader: Box<dyn Read + Send + Sync + 'static>,
+}
+
+impl Iterator for SelectEventStream {
+    type Item = Result<SelectEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = match self.read_message() {
+            Ok(Some(message)) => message.into_event(),
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => Err(e),
+        };
+        if matches!(result, Ok(SelectEvent::End) | Err(_)) {
+            self.done = true;
+        }
+        Some(result)
+    }
+}
+
+impl SelectEventStream {
+    fn read_message(&mut self) -> Result<Option<Message>> {
+        let mut prelude = [0; 12];
+        match self.reader.read_exact(&mut prelude[..1]) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        self.reader.read_exact(&mut prelude[1..])?;
+
+        let total_length = be_u32(&prelude[0..4]) as usize;
+        let headers_length = be_u32(&prelude[4..8]) as usize;
+        if crc32fast::hash(&prelude[..8]) != be_u32(&prelude[8..12]) {
+            return Err(invalid_data("event stream prelude checksum mismatch"));
+        }
+        if !(MIN_MESSAGE_LENGTH..=MAX_MESSAGE_LENGTH).contains(&total_length)
+            || headers_length > total_length - MIN_MESSAGE_LENGTH
+        {
+            return Err(invalid_data("event stream message length is invalid"));
+        }
+
+        let mut rest = vec![0; total_length - prelude.len()];
+        self.reader.read_exact(&mut rest)?;
+        let (body, message_crc) = rest.split_at(rest.len() - 4);
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&prelude);
+        hasher.update(body);
+        if hasher.finalize() != be_u32(message_crc) {
+            return Err(invalid_data("event stream message checksum mismatch"));
+        }
+
+        let (headers, payload) = body.split_at(headers_length);
+        Ok(Some(Message {
+            headers: parse_headers(headers)?,
+            payload: payload.to_vec(),
+        }))
+    }
+}
+
+struct Message {
+    headers: Vec<(String, String)>,
+    payload: Vec<u8>,
+}
+
+impl Message {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn into_event(self) -> Result<SelectEvent> {
+        if self.header(":message-type") == Some("error") {
+            return Err(Error::S3(ErrorBody {
+                code: self.header(":error-code").unwrap_or_default().into(),
+                message: self.header(":error-message").unwrap_or_default().into(),
+                ..Default::default()
+            }));
+        }
+        match self.header(":event-type") {
+            Some("Cont") => Ok(SelectEvent::Cont),
+            Some("End") => Ok(SelectEvent::End),
+            Some("Progress") => Ok(SelectEvent::Progress(serde_xml_rs::from_reader(
+                self.payload.as_slice(),
+            )?)),
+            Some("Records") => Ok(SelectEvent::Records(self.payload)),
+            Some("Stats") => Ok(SelectEvent::Stats(serde_xml_rs::from_reader(
+                self.payload.as_slice(),
+            )?)),
+            other => Err(invalid_data(&format!(
+                "unexpected event type {}",
+                other.unwrap_or("<none>")
+            ))),
+        }
+    }
+}
+
+fn parse_headers(mut bytes: &[u8]) -> Result<Vec<(String, String)>> {
+    let mut headers = Vec::new();
+    while !bytes.is_empty() {
+        let name_length = bytes[0] as usize;
+        let name = bytes
+            .get(1..1 + name_length)
+            .ok_or_else(|| invalid_data("event stream header is truncated"))?;
+        bytes = &bytes[1 + name_length..];
+        let (&value_type, rest) = bytes
+            .split_first()
+            .ok_or_else(|| invalid_data("event stream header is truncated"))?;
+        // Only string headers are sent by SelectObjectContent.
+        if value_type != 7 || rest.len() < 2 {
+            return Err(invalid_data("unsupported event stream header"));
+        }
+        let value_length = u16::from_be_bytes([rest[0], rest[1]]) as usize;
+        let value = rest
+            .get(2..2 + value_length)
+            .ok_or_else(|| invalid_data("event stream header is truncated"))?;
+        headers.push((
+            String::from_utf8_lossy(name).into_owned(),
+            String::from_utf8_lossy(value).into_owned(),
+        ));
+        bytes = &rest[2 + value_length..];
+    }
+    Ok(headers)
+}
+
+fn be_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::Io(io::Error::new(io::ErrorKind::InvalidData, message))
+}
+
+impl Api {
+    pub fn select_object_content(
+        &self,
+        input: SelectObjectContentInput,
+    ) -> Result<SelectObjectContentOutput> {
+        let mut req = ureq::post(&self.object_url(&input.bucket, &input.key));
+        req = req.query("select", "");
+        req = req.query("select-type", "2");
+        let body = xml::to_string(&input.request)?;
+        self.send(req, body.as_bytes())
+            .map(|response| SelectObjectContentOutput {
+                events: SelectEventStream {
+                    done: false,
+                    reader: response.into_reader(),
+                },
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend_from_slice(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend_from_slice(value.as_bytes());
+        }
+        let total_length = MIN_MESSAGE_LENGTH + encoded_headers.len() + payload.len();
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(total_length as u32).to_be_bytes());
+        bytes.extend_from_slice(&(encoded_headers.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&crc32fast::hash(&bytes).to_be_bytes());
+        bytes.extend_from_slice(&encoded_headers);
+        bytes.extend_from_slice(payload);
+        bytes.extend_from_slice(&crc32fast::hash(&bytes).to_be_bytes());
+        bytes
+    }
+
+    fn event(event_type: &str, payload: &[u8]) -> Vec<u8> {
+        message(
+            &[(":message-type", "event"), (":event-type", event_type)],
+            payload,
+        )
+    }
+
+    fn stream(bytes: Vec<u8>) -> SelectEventStream {
+        SelectEventStream {
+            done: false,
+            reader: Box::new(io::Cursor::new(bytes)),
+        }
+    }
+
+    fn is_invalid_data(result: Option<Result<SelectEvent>>) -> bool {
+        matches!(result, Some(Err(Error::Io(e))) if e.kind() == io::ErrorKind::InvalidData)
+    }
+
+    #[test]
+    fn decodes_events() {
+        let mut bytes = event("Records", b"a,b\n");
+        bytes.extend(event(
+            "Stats",
+            b"<Stats><BytesScanned>10</BytesScanned><BytesProcessed>10</BytesProcessed><BytesReturned>4</BytesReturned></Stats>",
+        ));
+        bytes.extend(event("End", b""));
+        bytes.extend(event("Records", b"ignored"));
+
+        let events = stream(bytes).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], SelectEvent::Records(r) if r == b"a,b\n"));
+        assert!(matches!(&events[1], SelectEvent::Stats(s) if s.bytes_returned == Some(4)));
+        assert!(matches!(events[2], SelectEvent::End));
+    }
+
+    #[test]
+    fn decodes_error_event() {
+        let bytes = message(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InvalidQuery"),
+                (":error-message", "bad query"),
+            ],
+            b"",
+        );
+        let mut events = stream(bytes);
+        match events.next() {
+            Some(Err(Error::S3(eb))) => {
+                assert_eq!(eb.code, "InvalidQuery");
+                assert_eq!(eb.message, "bad query");
+            }
+            other => panic!("unexpected result: {:?}", other.map(|r| r.is_ok())),
+        }
+        assert!(events.next().is_none());
+    }
+
+    #[test]
+    fn rejects_checksum_mismatch() {
+        let mut bytes = event("Records", b"data");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        assert!(is_invalid_data(stream(bytes).next()));
+
+        let mut bytes = event("Records", b"data");
+        bytes[3] ^= 1;
+        assert!(is_invalid_data(stream(bytes).next()));
+    }
+
+    #[test]
+    fn rejects_invalid_lengths() {
+        for total_length in [0, 11, 15, MAX_MESSAGE_LENGTH as u32 + 1, u32::MAX] {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&total_length.to_be_bytes());
+            bytes.extend_from_slice(&0u32.to_be_bytes());
+            bytes.extend_from_slice(&crc32fast::hash(&bytes).to_be_bytes());
+            assert!(is_invalid_data(stream(bytes).next()));
+        }
+    }
+}
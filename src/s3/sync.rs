@@ -0,0 +1,591 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use glob::Pattern;
+use md5::{Digest, Md5};
+
+use super::{Api, DeleteObjectInput, GetObjectInput, ListObjectsV2Input, Result, SERVICE_NAME};
+use crate::request::{self, sign_streaming_request};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SyncDirection {
+    #[default]
+    Upload,
+    Download,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SyncInput {
+    pub bucket: String,
+    pub delete: bool,
+    pub direction: SyncDirection,
+    pub dry_run: bool,
+    pub exclude: Vec<String>,
+    pub include: Vec<String>,
+    pub local_path: PathBuf,
+    pub prefix: String,
+}
+
+impl SyncInput {
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.bucket = bucket.to_string();
+        self
+    }
+
+    pub fn delete(mut self, delete: bool) -> Self {
+        self.delete = delete;
+        self
+    }
+
+    pub fn direction(mut self, direction: SyncDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    pub fn include(mut self, pattern: &str) -> Self {
+        self.include.push(pattern.to_string());
+        self
+    }
+
+    pub fn local_path<P: AsRef<Path>>(mut self, local_path: P) -> Self {
+        self.local_path = local_path.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyncAction {
+    Upload { path: PathBuf, key: String },
+    Download { key: String, path: PathBuf },
+    DeleteLocal { path: PathBuf },
+    DeleteRemote { key: String },
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SyncOutput {
+    pub actions: Vec<SyncAction>,
+    // Remote keys that do not map to a path inside the local directory, and
+    // local symlinks that are not followed.
+    pub skipped: Vec<String>,
+}
+
+struct Entry {
+    e_tag: Option<String>,
+    modified: Option<DateTime<Utc>>,
+    size: u64,
+}
+
+struct Filter {
+    exclude: Vec<Pattern>,
+    include: Vec<Pattern>,
+}
+
+impl Filter {
+    fn new(include: &[String], exclude: &[String]) -> Result<Self> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| Pattern::new(p))
+                .collect::<std::result::Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            exclude: compile(exclude)?,
+            include: compile(include)?,
+        })
+    }
+
+    fn matches(&self, relative: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|p| p.matches(relative));
+        included && !self.exclude.iter().any(|p| p.matches(relative))
+    }
+}
+
+impl Api {
+    pub fn sync(&self, input: SyncInput) -> Result<SyncOutput> {
+        let filter = Filter::new(&input.include, &input.exclude)?;
+        let prefix = match input.prefix.as_str() {
+            "" => String::new(),
+            p if p.ends_with('/') => p.to_string(),
+            p => format!("{}/", p),
+        };
+
+        let mut skipped = Vec::new();
+        let local = local_entries(&input.local_path, &filter, &mut skipped)?;
+        let remote = self.remote_entries(&input.bucket, &prefix, &filter)?;
+        let mut output = plan(&input, &prefix, &local, &remote)?;
+        output.skipped.extend(skipped);
+
+        if !input.dry_run {
+            for action in &output.actions {
+                self.apply(&input.bucket, action, &remote, &prefix)?;
+            }
+        }
+        Ok(output)
+    }
+
+    fn remote_entries(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        filter: &Filter,
+    ) -> Result<BTreeMap<String, Entry>> {
+        let mut entries = BTreeMap::new();
+        let mut input = ListObjectsV2Input::default().bucket(bucket);
+        if !prefix.is_empty() {
+            input = input.prefix(prefix);
+        }
+        for page in self.list_objects_v2_paginator(input) {
+            for object in page?.contents.unwrap_or_default() {
+                let Some(key) = object.key else {
+                    continue;
+                };
+                let relative = key.strip_prefix(prefix).unwrap_or(&key);
+                if relative.is_empty() || relative.ends_with('/') || !filter.matches(relative) {
+                    continue;
+                }
+                entries.insert(
+                    relative.to_string(),
+                    Entry {
+                        e_tag: object.e_tag.and_then(|e| e.into_iter().next()),
+                        modified: object.last_modified,
+                        size: object.size.unwrap_or_default() as u64,
+                    },
+                );
+            }
+        }
+        Ok(entries)
+    }
+
+    fn apply(
+        &self,
+        bucket: &str,
+        action: &SyncAction,
+        remote: &BTreeMap<String, Entry>,
+        prefix: &str,
+    ) -> Result<()> {
+        match action {
+            SyncAction::Upload { path, key } => self.upload_file(bucket, key, path)?,
+            SyncAction::Download { key, path } => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut output =
+                    self.get_object(GetObjectInput::default().bucket(bucket).key(key))?;
+                let mut file = File::create(path)?;
+                io::copy(&mut output.body, &mut file)?;
+                let modified = key
+                    .strip_prefix(prefix)
+                    .and_then(|relative| remote.get(relative))
+                    .and_then(|entry| entry.modified);
+                if let Some(modified) = modified {
+                    file.set_modified(modified.into())?;
+                }
+            }
+            SyncAction::DeleteLocal { path } => fs::remove_file(path)?,
+            SyncAction::DeleteRemote { key } => {
+                self.delete_object(DeleteObjectInput::default().bucket(bucket).key(key))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Streams the file into the request instead of reading it into memory.
+    fn upload_file(&self, bucket: &str, key: &str, path: &Path) -> Result<()> {
+        let length = fs::metadata(path)?.len();
+        let mut req = ureq::put(&self.object_url(bucket, key));
+        req = req.set("Content-Length", &length.to_string());
+        let identity = self.credentials.clone().into();
+        req = sign_streaming_request(req, &identity, &self.region, SERVICE_NAME)?;
+        req.send(File::open(path)?).map_err(request::Error::from)?;
+        Ok(())
+    }
+}
+
+fn plan(
+    input: &SyncInput,
+    prefix: &str,
+    local: &BTreeMap<String, Entry>,
+    remote: &BTreeMap<String, Entry>,
+) -> Result<SyncOutput> {
+    let mut output = SyncOutput::default();
+    match input.direction {
+        SyncDirection::Upload => {
+            for (relative, source) in local {
+                let path = input.local_path.join(relative);
+                if needs_transfer(source, remote.get(relative), &path)? {
+                    output.actions.push(SyncAction::Upload {
+                        path,
+                        key: format!("{}{}", prefix, relative),
+                    });
+                }
+            }
+            if input.delete {
+                for relative in remote.keys().filter(|r| !local.contains_key(*r)) {
+                    output.actions.push(SyncAction::DeleteRemote {
+                        key: format!("{}{}", prefix, relative),
+                    });
+                }
+            }
+        }
+        SyncDirection::Download => {
+            for (relative, source) in remote {
+                let Some(relative_path) = local_relative_path(relative) else {
+                    output.skipped.push(format!("{}{}", prefix, relative));
+                    continue;
+                };
+                let path = input.local_path.join(relative_path);
+                if needs_transfer(source, local.get(relative), &path)? {
+                    output.actions.push(SyncAction::Download {
+                        key: format!("{}{}", prefix, relative),
+                        path,
+                    });
+                }
+            }
+            if input.delete {
+                for relative in local.keys().filter(|r| !remote.contains_key(*r)) {
+                    output.actions.push(SyncAction::DeleteLocal {
+                        path: input.local_path.join(relative),
+                    });
+                }
+            }
+        }
+    }
+    Ok(output)
+}
+
+// Keys such as "../x" or "/etc/passwd" would resolve outside the local
+// directory when joined, so only plain path components are accepted.
+fn local_relative_path(relative: &str) -> Option<&Path> {
+    let path = Path::new(relative);
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_)))
+        .then_some(path)
+}
+
+fn local_entries(
+    root: &Path,
+    filter: &Filter,
+    skipped: &mut Vec<String>,
+) -> Result<BTreeMap<String, Entry>> {
+    let mut entries = BTreeMap::new();
+    if !root.exists() {
+        return Ok(entries);
+    }
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for dir_entry in fs::read_dir(&dir)? {
+            let path = dir_entry?.path();
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            // Symlinked directories are not followed, since a link to a parent
+            // directory would otherwise be walked forever, and broken links
+            // have nothing to sync.
+            let mut metadata = fs::symlink_metadata(&path)?;
+            if metadata.file_type().is_symlink() {
+                match fs::metadata(&path) {
+                    Ok(target) if !target.is_dir() => metadata = target,
+                    _ => {
+                        skipped.push(relative);
+                        continue;
+                    }
+                }
+            }
+            if metadata.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if !filter.matches(&relative) {
+                continue;
+            }
+            entries.insert(
+                relative,
+                Entry {
+                    e_tag: None,
+                    modified: metadata.modified().ok().map(Into::into),
+                    size: metadata.len(),
+                },
+            );
+        }
+    }
+    Ok(entries)
+}
+
+fn needs_transfer(source: &Entry, destination: Option<&Entry>, local_path: &Path) -> Result<bool> {
+    let Some(destination) = destination else {
+        return Ok(true);
+    };
+    if source.size != destination.size {
+        return Ok(true);
+    }
+    if source.modified <= destination.modified {
+        return Ok(false);
+    }
+    let e_tag = source.e_tag.as_ref().or(destination.e_tag.as_ref());
+    match e_tag {
+        Some(e_tag) => Ok(!matches_e_tag(local_path, e_tag)?),
+        None => Ok(true),
+    }
+}
+
+fn matches_e_tag(path: &Path, e_tag: &str) -> Result<bool> {
+    let e_tag = e_tag.trim_matches('"');
+    if e_tag.contains('-') {
+        return Ok(false);
+    }
+    let mut file = File::open(path)?;
+    let mut hasher = Md5::new();
+    let mut buf = [0; 8192];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    let digest = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    Ok(digest == e_tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("minaws-sync-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn at(timestamp: i64) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(timestamp, 0)
+    }
+
+    fn entry(size: u64, modified: i64, e_tag: Option<&str>) -> Entry {
+        Entry {
+            e_tag: e_tag.map(Into::into),
+            modified: at(modified),
+            size,
+        }
+    }
+
+    fn entries(items: Vec<(&str, Entry)>) -> BTreeMap<String, Entry> {
+        items.into_iter().map(|(k, v)| (k.to_string(), v)).collect()
+    }
+
+    fn input(direction: SyncDirection, local_path: &Path) -> SyncInput {
+        SyncInput::default()
+            .bucket("bucket")
+            .direction(direction)
+            .local_path(local_path)
+    }
+
+    #[test]
+    fn upload_new_and_changed_files() {
+        let root = Path::new("/data");
+        let local = entries(vec![
+            ("new.txt", entry(1, 10, None)),
+            ("resized.txt", entry(2, 10, None)),
+            ("same.txt", entry(3, 10, None)),
+        ]);
+        let remote = entries(vec![
+            ("resized.txt", entry(5, 20, Some("\"abc\""))),
+            ("same.txt", entry(3, 20, Some("\"abc\""))),
+        ]);
+        let output = plan(&input(SyncDirection::Upload, root), "p/", &local, &remote).unwrap();
+        assert_eq!(
+            output.actions,
+            vec![
+                SyncAction::Upload {
+                    path: root.join("new.txt"),
+                    key: "p/new.txt".into(),
+                },
+                SyncAction::Upload {
+                    path: root.join("resized.txt"),
+                    key: "p/resized.txt".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn upload_deletes_remote_only_when_requested() {
+        let root = Path::new("/data");
+        let local = entries(vec![]);
+        let remote = entries(vec![("old.txt", entry(1, 10, None))]);
+        let output = plan(&input(SyncDirection::Upload, root), "p/", &local, &remote).unwrap();
+        assert!(output.actions.is_empty());
+
+        let output = plan(
+            &input(SyncDirection::Upload, root).delete(true),
+            "p/",
+            &local,
+            &remote,
+        )
+        .unwrap();
+        assert_eq!(
+            output.actions,
+            vec![SyncAction::DeleteRemote {
+                key: "p/old.txt".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn download_and_delete_local() {
+        let root = Path::new("/data");
+        let local = entries(vec![("stale.txt", entry(1, 10, None))]);
+        let remote = entries(vec![("dir/new.txt", entry(1, 10, None))]);
+        let output = plan(
+            &input(SyncDirection::Download, root).delete(true),
+            "",
+            &local,
+            &remote,
+        )
+        .unwrap();
+        assert_eq!(
+            output.actions,
+            vec![
+                SyncAction::Download {
+                    key: "dir/new.txt".into(),
+                    path: root.join("dir/new.txt"),
+                },
+                SyncAction::DeleteLocal {
+                    path: root.join("stale.txt"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn download_skips_keys_outside_local_path() {
+        let root = Path::new("/data");
+        let remote = entries(vec![
+            ("../../.bashrc", entry(1, 10, None)),
+            ("/etc/passwd", entry(1, 10, None)),
+            ("./b", entry(1, 10, None)),
+            ("ok.txt", entry(1, 10, None)),
+        ]);
+        let output = plan(
+            &input(SyncDirection::Download, root),
+            "prefix/",
+            &entries(vec![]),
+            &remote,
+        )
+        .unwrap();
+        assert_eq!(
+            output.actions,
+            vec![SyncAction::Download {
+                key: "prefix/ok.txt".into(),
+                path: root.join("ok.txt"),
+            }]
+        );
+        assert_eq!(
+            output.skipped,
+            vec!["prefix/../../.bashrc", "prefix/./b", "prefix//etc/passwd"]
+        );
+    }
+
+    #[test]
+    fn newer_source_with_matching_e_tag_is_skipped() {
+        let root = temp_dir("etag");
+        fs::write(root.join("a.txt"), b"hello").unwrap();
+        // md5("hello")
+        let e_tag = "\"5d41402abc4b2a76b9719d911017c592\"";
+        let local = entries(vec![("a.txt", entry(5, 10, None))]);
+
+        let remote = entries(vec![("a.txt", entry(5, 20, Some(e_tag)))]);
+        let output = plan(&input(SyncDirection::Download, &root), "", &local, &remote).unwrap();
+        assert!(output.actions.is_empty());
+
+        let remote = entries(vec![("a.txt", entry(5, 20, Some("\"0123\"")))]);
+        let output = plan(&input(SyncDirection::Download, &root), "", &local, &remote).unwrap();
+        assert_eq!(output.actions.len(), 1);
+
+        // Multipart ETags are not an MD5 of the content.
+        let remote = entries(vec![("a.txt", entry(5, 20, Some("\"abc-2\"")))]);
+        let output = plan(&input(SyncDirection::Download, &root), "", &local, &remote).unwrap();
+        assert_eq!(output.actions.len(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn local_entries_filters_paths() {
+        let root = temp_dir("filter");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("a.txt"), b"a").unwrap();
+        fs::write(root.join("b.log"), b"b").unwrap();
+        fs::write(root.join("sub/c.txt"), b"c").unwrap();
+        let filter = Filter::new(&["*.txt".into()], &["sub/*".into()]).unwrap();
+        let mut skipped = Vec::new();
+        let entries = local_entries(&root, &filter, &mut skipped).unwrap();
+        assert_eq!(entries.keys().collect::<Vec<_>>(), vec!["a.txt"]);
+        assert!(skipped.is_empty());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn local_entries_does_not_follow_directory_symlinks() {
+        let root = temp_dir("symlink");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("sub/a.txt"), b"a").unwrap();
+        std::os::unix::fs::symlink(&root, root.join("sub/loop")).unwrap();
+        let filter = Filter::new(&[], &[]).unwrap();
+        let mut skipped = Vec::new();
+        let entries = local_entries(&root, &filter, &mut skipped).unwrap();
+        assert_eq!(entries.keys().collect::<Vec<_>>(), vec!["sub/a.txt"]);
+        assert_eq!(skipped, vec!["sub/loop"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn local_entries_skips_dangling_symlinks() {
+        let root = temp_dir("dangling");
+        fs::write(root.join("a.txt"), b"a").unwrap();
+        fs::write(root.join("b.txt"), b"b").unwrap();
+        std::os::unix::fs::symlink(root.join("missing.txt"), root.join("broken.txt")).unwrap();
+        std::os::unix::fs::symlink(root.join("b.txt"), root.join("link.txt")).unwrap();
+        let filter = Filter::new(&[], &[]).unwrap();
+        let mut skipped = Vec::new();
+        let entries = local_entries(&root, &filter, &mut skipped).unwrap();
+        assert_eq!(
+            entries.keys().collect::<Vec<_>>(),
+            vec!["a.txt", "b.txt", "link.txt"]
+        );
+        assert_eq!(skipped, vec!["broken.txt"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,447 @@
+use serde::{
+    ser::{self, Impossible},
+    Serialize,
+};
+use serde_xml_rs::Error;
+
+type Result<T> = std::result::Result<T, Error>;
+
+// Writes S3 request bodies. serde-xml-rs 0.6 can only serialize a sequence
+// when it knows the name of the enclosing element, so bodies with lists of
+// structs such as Tagging fail with LastElementNameNotAvailable. Here a field
+// holding a sequence repeats the field name for each element, which is how S3
+// expects lists to be written.
+pub(super) fn to_string<T: Serialize>(value: &T) -> Result<String> {
+    let mut out = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    value.serialize(ElementSerializer {
+        out: &mut out,
+        name: None,
+    })?;
+    Ok(out)
+}
+
+fn unsupported<T>(operation: &str) -> Result<T> {
+    Err(Error::UnsupportedOperation {
+        operation: operation.into(),
+    })
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct ElementSerializer<'a> {
+    out: &'a mut String,
+    name: Option<&'a str>,
+}
+
+impl<'a> ElementSerializer<'a> {
+    fn element_name(&self) -> Result<&'a str> {
+        self.name.map_or_else(|| unsupported("root value"), Ok)
+    }
+
+    fn text(self, text: &str) -> Result<()> {
+        let name = self.element_name()?;
+        self.out
+            .push_str(&format!("<{}>{}</{}>", name, escape(text), name));
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for ElementSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.text(&v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.text(v)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
+        unsupported("bytes")
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.text("")
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.text("")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.text(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        unsupported("newtype variant")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(SeqSerializer {
+            name: self.element_name()?,
+            out: self.out,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        unsupported("tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unsupported("tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unsupported("tuple variant")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        unsupported("map")
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        let name = self.name.unwrap_or(name);
+        self.out.push_str(&format!("<{}>", name));
+        Ok(StructSerializer {
+            name,
+            out: self.out,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unsupported("struct variant")
+    }
+}
+
+struct SeqSerializer<'a> {
+    name: &'a str,
+    out: &'a mut String,
+}
+
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(ElementSerializer {
+            out: self.out,
+            name: Some(self.name),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct StructSerializer<'a> {
+    name: &'a str,
+    out: &'a mut String,
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(ElementSerializer {
+            out: self.out,
+            name: Some(key),
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        self.out.push_str(&format!("</{}>", self.name));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_with::skip_serializing_none;
+
+    use super::*;
+
+    const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+    #[derive(Serialize)]
+    struct Tag {
+        #[serde(rename = "Key")]
+        key: String,
+        #[serde(rename = "Value")]
+        value: String,
+    }
+
+    #[derive(Serialize)]
+    struct TagSet {
+        #[serde(rename = "Tag")]
+        tags: Vec<Tag>,
+    }
+
+    #[derive(Serialize)]
+    struct Tagging {
+        #[serde(rename = "TagSet")]
+        tag_set: TagSet,
+    }
+
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn escapes_text() {
+        let tagging = Tagging {
+            tag_set: TagSet {
+                tags: vec![tag("a&b", r#"<"quoted" 'text'>"#)],
+            },
+        };
+        assert_eq!(
+            to_string(&tagging).unwrap(),
+            format!(
+                "{}<Tagging><TagSet><Tag><Key>a&amp;b</Key>\
+                 <Value>&lt;&quot;quoted&quot; &apos;text&apos;&gt;</Value>\
+                 </Tag></TagSet></Tagging>",
+                DECLARATION
+            )
+        );
+    }
+
+    #[test]
+    fn repeats_vec_fields() {
+        let tagging = Tagging {
+            tag_set: TagSet {
+                tags: vec![tag("a", "1"), tag("b", "2")],
+            },
+        };
+        assert_eq!(
+            to_string(&tagging).unwrap(),
+            format!(
+                "{}<Tagging><TagSet>\
+                 <Tag><Key>a</Key><Value>1</Value></Tag>\
+                 <Tag><Key>b</Key><Value>2</Value></Tag>\
+                 </TagSet></Tagging>",
+                DECLARATION
+            )
+        );
+    }
+
+    #[test]
+    fn nests_structs_and_skips_none() {
+        #[derive(Serialize)]
+        struct Rule {
+            #[serde(rename = "ID")]
+            id: String,
+            #[serde(rename = "Expiration")]
+            expiration: Expiration,
+        }
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Expiration {
+            #[serde(rename = "Days")]
+            days: Option<u32>,
+            #[serde(rename = "Date")]
+            date: Option<String>,
+        }
+        #[derive(Serialize)]
+        struct LifecycleConfiguration {
+            #[serde(rename = "Rule")]
+            rules: Vec<Rule>,
+            #[serde(rename = "Status")]
+            status: Option<String>,
+        }
+        let configuration = LifecycleConfiguration {
+            rules: vec![Rule {
+                id: "expire".into(),
+                expiration: Expiration {
+                    days: Some(30),
+                    date: None,
+                },
+            }],
+            status: None,
+        };
+        assert_eq!(
+            to_string(&configuration).unwrap(),
+            format!(
+                "{}<LifecycleConfiguration><Rule><ID>expire</ID>\
+                 <Expiration><Days>30</Days></Expiration>\
+                 </Rule></LifecycleConfiguration>",
+                DECLARATION
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_types() {
+        #[derive(Serialize)]
+        struct WithMap {
+            map: BTreeMap<String, String>,
+        }
+        #[derive(Serialize)]
+        struct WithTuple {
+            tuple: (u32, u32),
+        }
+        #[derive(Serialize)]
+        enum Choice {
+            Newtype(u32),
+        }
+        #[derive(Serialize)]
+        struct WithVariant {
+            choice: Choice,
+        }
+        struct Bytes;
+        impl Serialize for Bytes {
+            fn serialize<S: ser::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(b"data")
+            }
+        }
+        #[derive(Serialize)]
+        struct WithBytes {
+            bytes: Bytes,
+        }
+
+        let is_unsupported = |result: Result<String>, expected: &str| matches!(result, Err(Error::UnsupportedOperation { operation }) if operation == expected);
+        assert!(is_unsupported(to_string(&"text"), "root value"));
+        assert!(is_unsupported(to_string(&vec![1]), "root value"));
+        assert!(is_unsupported(
+            to_string(&WithMap {
+                map: BTreeMap::new()
+            }),
+            "map"
+        ));
+        assert!(is_unsupported(
+            to_string(&WithTuple { tuple: (1, 2) }),
+            "tuple"
+        ));
+        assert!(is_unsupported(
+            to_string(&WithVariant {
+                choice: Choice::Newtype(1)
+            }),
+            "newtype variant"
+        ));
+        assert!(is_unsupported(
+            to_string(&WithBytes { bytes: Bytes }),
+            "bytes"
+        ));
+    }
+}
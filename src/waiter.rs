@@ -0,0 +1,234 @@
+use std::{
+    io, thread,
+    time::{Duration, Instant},
+};
+
+type Matcher<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaiterState {
+    Success,
+    Failure,
+    Retry,
+}
+
+pub struct Waiter<'a, T, E> {
+    description: String,
+    errors: Vec<(WaiterState, Matcher<'a, E>)>,
+    failure: Vec<Matcher<'a, T>>,
+    max_delay: Duration,
+    min_delay: Duration,
+    success: Vec<Matcher<'a, T>>,
+    timeout: Duration,
+}
+
+impl<'a, T, E> Waiter<'a, T, E>
+where
+    E: From<io::Error>,
+{
+    pub fn new(description: &str) -> Self {
+        Self {
+            description: description.into(),
+            errors: Vec::new(),
+            failure: Vec::new(),
+            max_delay: Duration::from_secs(120),
+            min_delay: Duration::from_secs(15),
+            success: Vec::new(),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    pub fn failure<F: Fn(&T) -> bool + 'a>(mut self, matcher: F) -> Self {
+        self.failure.push(Box::new(matcher));
+        self
+    }
+
+    pub fn failure_error<F: Fn(&E) -> bool + 'a>(mut self, matcher: F) -> Self {
+        self.errors.push((WaiterState::Failure, Box::new(matcher)));
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    pub fn retry_error<F: Fn(&E) -> bool + 'a>(mut self, matcher: F) -> Self {
+        self.errors.push((WaiterState::Retry, Box::new(matcher)));
+        self
+    }
+
+    pub fn success<F: Fn(&T) -> bool + 'a>(mut self, matcher: F) -> Self {
+        self.success.push(Box::new(matcher));
+        self
+    }
+
+    pub fn success_error<F: Fn(&E) -> bool + 'a>(mut self, matcher: F) -> Self {
+        self.errors.push((WaiterState::Success, Box::new(matcher)));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // Outputs that match no acceptor are retried, errors that match no
+    // acceptor are returned to the caller. An error accepted as success
+    // returns the default output, since there is no output to return.
+    pub fn wait<F>(&self, mut operation: F) -> Result<T, E>
+    where
+        F: FnMut() -> Result<T, E>,
+        T: Default,
+    {
+        let start = Instant::now();
+        let mut delay = self.min_delay;
+        loop {
+            let (state, output) = match operation() {
+                Ok(output) => (self.output_state(&output), Some(output)),
+                Err(e) => match self.error_state(&e) {
+                    Some(state) => (state, None),
+                    None => return Err(e),
+                },
+            };
+            match state {
+                WaiterState::Success => return Ok(output.unwrap_or_default()),
+                WaiterState::Failure => {
+                    return Err(io::Error::other(format!(
+                        "failure state reached waiting for {}",
+                        self.description
+                    ))
+                    .into());
+                }
+                WaiterState::Retry => {}
+            }
+            // The last delay is cut short so the operation is tried once more
+            // before the timeout.
+            let remaining = self.timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out waiting for {}", self.description),
+                )
+                .into());
+            }
+            thread::sleep(delay.min(remaining));
+            delay = (delay * 2).min(self.max_delay);
+        }
+    }
+
+    fn output_state(&self, output: &T) -> WaiterState {
+        if self.success.iter().any(|m| m(output)) {
+            WaiterState::Success
+        } else if self.failure.iter().any(|m| m(output)) {
+            WaiterState::Failure
+        } else {
+            WaiterState::Retry
+        }
+    }
+
+    fn error_state(&self, err: &E) -> Option<WaiterState> {
+        self.errors
+            .iter()
+            .find(|(_, m)| m(err))
+            .map(|(state, _)| *state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn waiter<'a>() -> Waiter<'a, u32, io::Error> {
+        Waiter::new("test")
+            .min_delay(Duration::ZERO)
+            .max_delay(Duration::ZERO)
+            .timeout(Duration::from_secs(5))
+            .success(|n: &u32| *n == 3)
+            .failure(|n| *n == 99)
+    }
+
+    fn not_found() -> io::Error {
+        io::Error::from(io::ErrorKind::NotFound)
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let calls = Cell::new(0);
+        let result = waiter().wait(|| {
+            calls.set(calls.get() + 1);
+            Ok(calls.get())
+        });
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn failure_output() {
+        let err = waiter().wait(|| Ok(99)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn error_states() {
+        let result = waiter()
+            .success_error(|e: &io::Error| e.kind() == io::ErrorKind::NotFound)
+            .wait(|| Err(not_found()));
+        assert_eq!(result.unwrap(), 0);
+
+        let err = waiter()
+            .failure_error(|e: &io::Error| e.kind() == io::ErrorKind::NotFound)
+            .wait(|| Err(not_found()))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+
+        let calls = Cell::new(0);
+        let result = waiter()
+            .retry_error(|e: &io::Error| e.kind() == io::ErrorKind::NotFound)
+            .wait(|| {
+                calls.set(calls.get() + 1);
+                match calls.get() {
+                    3 => Ok(3),
+                    _ => Err(not_found()),
+                }
+            });
+        assert_eq!(result.unwrap(), 3);
+
+        let err = waiter().wait(|| Err(not_found())).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn polls_once_more_before_timeout() {
+        let calls = Cell::new(0);
+        let result = waiter()
+            .min_delay(Duration::from_secs(60))
+            .max_delay(Duration::from_secs(60))
+            .timeout(Duration::from_millis(50))
+            .wait(|| {
+                calls.set(calls.get() + 1);
+                Ok(calls.get() + 1)
+            });
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.get(), 2);
+
+        let calls = Cell::new(0);
+        let err = waiter()
+            .min_delay(Duration::from_secs(60))
+            .timeout(Duration::from_millis(50))
+            .wait(|| {
+                calls.set(calls.get() + 1);
+                Ok(0)
+            })
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(calls.get(), 2);
+    }
+}